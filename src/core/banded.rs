@@ -0,0 +1,536 @@
+use std::fmt;
+use crate::core::matrix::Matrix;
+use crate::core::vector::Vector;
+use crate::operations::{Field, Operations};
+
+// Square matrix with zeros outside the diagonal and its two neighbours
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tridiagonal<K> {
+    // Cells (i + 1, i)
+    lower: Vec<K>,
+    // Cells (i, i)
+    diagonal: Vec<K>,
+    // Cells (i, i + 1)
+    upper: Vec<K>,
+}
+
+// Square matrix whose cell (r, c) is zero unless r - lower <= c <= r + upper.
+// Row r keeps its lower + upper + 1 band cells, from column r - lower on,
+// so cells before the first column or after the last one are never used.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Banded<K> {
+    size: usize,
+    lower: usize,
+    upper: usize,
+    data: Vec<K>,
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > Tridiagonal<K>
+{
+    pub fn new(lower: Vec<K>, diagonal: Vec<K>, upper: Vec<K>) -> Tridiagonal<K> {
+        let n = diagonal.len();
+        if n == 0 || lower.len() != n - 1 || upper.len() != n - 1 {
+            panic!(
+                "Diagonals of lengths {}, {} and {} do not form a tridiagonal matrix",
+                lower.len(),
+                n,
+                upper.len()
+            );
+        }
+        return Tridiagonal { lower, diagonal, upper };
+    }
+
+    pub fn size(&self) -> usize {
+        return self.diagonal.len();
+    }
+
+    pub fn get(&self, r: usize, c: usize) -> K {
+        if r >= self.size() || c >= self.size() {
+            panic!("Cell ({}, {}) is out of bounds for size {}", r, c, self.size());
+        }
+        if r == c {
+            return self.diagonal[r];
+        } else if r == c + 1 {
+            return self.lower[c];
+        } else if c == r + 1 {
+            return self.upper[r];
+        }
+        return K::default();
+    }
+
+    pub fn set(&mut self, r: usize, c: usize, value: K) {
+        if r >= self.size() || c >= self.size() {
+            panic!("Cell ({}, {}) is out of bounds for size {}", r, c, self.size());
+        }
+        if r == c {
+            self.diagonal[r] = value;
+        } else if r == c + 1 {
+            self.lower[c] = value;
+        } else if c == r + 1 {
+            self.upper[r] = value;
+        } else {
+            panic!("Cell ({}, {}) is not stored in a tridiagonal matrix", r, c);
+        }
+    }
+
+    // Time: O(n) − Space: O(n)
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        let v = vec.flat();
+        let n = self.size();
+        if v.len() != n {
+            panic!("Sizes {} and {} are incompatible", n, v.len());
+        }
+        let result: Vec<K> = (0..n)
+            .map(|i| {
+                let mut cell = self.diagonal[i] * v[i];
+                if i > 0 {
+                    cell = cell + self.lower[i - 1] * v[i - 1];
+                }
+                if i + 1 < n {
+                    cell = cell + self.upper[i] * v[i + 1];
+                }
+                cell
+            })
+            .collect();
+        return Vector::from(result);
+    }
+
+    // Leading minors by the continuant recurrence
+    // f_i = d_i f_(i-1) - l_(i-1) u_(i-1) f_(i-2), without any division
+    // Time: O(n) − Space: O(1)
+    pub fn determinant(&self) -> K {
+        let (mut previous, mut current) = (K::one(), self.diagonal[0]);
+        for i in 1..self.size() {
+            let next = self.diagonal[i] * current - self.lower[i - 1] * self.upper[i - 1] * previous;
+            previous = current;
+            current = next;
+        }
+        return current;
+    }
+
+    // Thomas algorithm: Gaussian elimination without pivoting, which is
+    // stable for diagonally dominant or symmetric positive definite matrices.
+    // A zero pivot falls back to the band elimination with row swaps.
+    // Time: O(n) − Space: O(n)
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>, &'static str>
+    where
+        K: Field,
+    {
+        let b = b.flat();
+        let n = self.size();
+        if b.len() != n {
+            return Err("The right-hand side does not match the matrix");
+        }
+
+        // Eliminate the lower diagonal: row i becomes x_i + c_i x_(i+1) = d_i
+        let mut c: Vec<K> = vec![K::default(); n];
+        let mut d: Vec<K> = vec![K::default(); n];
+        for i in 0..n {
+            let mut pivot = self.diagonal[i];
+            let mut rhs = b[i];
+            if i > 0 {
+                pivot = pivot - self.lower[i - 1] * c[i - 1];
+                rhs = rhs - self.lower[i - 1] * d[i - 1];
+            }
+            if pivot == K::default() {
+                return Banded::from(self).solve(&Vector::from(b));
+            }
+            if i + 1 < n {
+                c[i] = self.upper[i] / pivot;
+            }
+            d[i] = rhs / pivot;
+        }
+
+        let mut x = d;
+        for i in (0..n - 1).rev() {
+            x[i] = x[i] - c[i] * x[i + 1];
+        }
+        return Ok(Vector::from(x));
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > Banded<K>
+{
+    pub fn new(size: usize, lower: usize, upper: usize) -> Banded<K> {
+        return Banded {
+            size,
+            lower,
+            upper,
+            data: vec![K::default(); size * (lower + upper + 1)],
+        };
+    }
+
+    pub fn size(&self) -> usize {
+        return self.size;
+    }
+
+    // (lower, upper) numbers of diagonals kept below and above the main one
+    pub fn bandwidths(&self) -> (usize, usize) {
+        return (self.lower, self.upper);
+    }
+
+    fn in_band(&self, r: usize, c: usize) -> bool {
+        return c + self.lower >= r && c <= r + self.upper;
+    }
+
+    fn index(&self, r: usize, c: usize) -> usize {
+        return r * (self.lower + self.upper + 1) + c + self.lower - r;
+    }
+
+    pub fn get(&self, r: usize, c: usize) -> K {
+        if r >= self.size || c >= self.size {
+            panic!("Cell ({}, {}) is out of bounds for size {}", r, c, self.size);
+        }
+        if !self.in_band(r, c) {
+            return K::default();
+        }
+        return self.data[self.index(r, c)];
+    }
+
+    pub fn set(&mut self, r: usize, c: usize, value: K) {
+        if r >= self.size || c >= self.size || !self.in_band(r, c) {
+            panic!(
+                "Cell ({}, {}) is not stored with bandwidths {:?}",
+                r,
+                c,
+                self.bandwidths()
+            );
+        }
+        let index = self.index(r, c);
+        self.data[index] = value;
+    }
+
+    // Columns of row r inside both the band and the matrix
+    fn band_columns(&self, r: usize) -> std::ops::Range<usize> {
+        return r.saturating_sub(self.lower)..(r + self.upper + 1).min(self.size);
+    }
+
+    // Time: O(n * (lower + upper)) − Space: O(n)
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        let v = vec.flat();
+        if v.len() != self.size {
+            panic!("Sizes {} and {} are incompatible", self.size, v.len());
+        }
+        let result: Vec<K> = (0..self.size)
+            .map(|r| self.band_columns(r).fold(K::default(), |s, c| s + self.get(r, c) * v[c]))
+            .collect();
+        return Vector::from(result);
+    }
+
+    // LU with partial pivoting, stored in place: the multipliers of step k
+    // below the diagonal of column k, U on and above the diagonal.
+    // A row swap pulls up to lower cells past the band, so U is kept with
+    // lower + upper diagonals. At step k, row k was swapped with pivots[k].
+    // Time: O(n * lower * (lower + upper)) − Space: O(n * (2 * lower + upper))
+    fn lu(&self) -> Result<(Banded<K>, Vec<usize>), &'static str>
+    where
+        K: Field,
+    {
+        let n = self.size;
+        let mut lu: Banded<K> = Banded::new(n, self.lower, self.lower + self.upper);
+        for r in 0..n {
+            for c in self.band_columns(r) {
+                lu.set(r, c, self.get(r, c));
+            }
+        }
+
+        let mut pivots: Vec<usize> = Vec::with_capacity(n);
+        for k in 0..n {
+            let last = (k + lu.lower + 1).min(n);
+            let end = (k + lu.upper + 1).min(n);
+
+            // Largest cell (in absolute value) of column k, on or below the diagonal
+            let mut p = k;
+            for i in k + 1..last {
+                if lu.get(i, k).abs() > lu.get(p, k).abs() {
+                    p = i;
+                }
+            }
+            if lu.get(p, k) == K::default() {
+                return Err("The matrix is singular");
+            }
+            if p != k {
+                for j in k..end {
+                    let cell = lu.get(k, j);
+                    lu.set(k, j, lu.get(p, j));
+                    lu.set(p, j, cell);
+                }
+            }
+            pivots.push(p);
+
+            let pivot = lu.get(k, k);
+            for i in k + 1..last {
+                let factor = lu.get(i, k) / pivot;
+                lu.set(i, k, factor);
+                for j in k + 1..end {
+                    let cell = lu.get(i, j) - factor * lu.get(k, j);
+                    lu.set(i, j, cell);
+                }
+            }
+        }
+        return Ok((lu, pivots));
+    }
+
+    // ± the product of the diagonal of U, zero when the matrix is singular
+    // Time: O(n * lower * (lower + upper)) − Space: O(n * (2 * lower + upper))
+    pub fn determinant(&self) -> K
+    where
+        K: Field,
+    {
+        let (lu, pivots) = match self.lu() {
+            Ok(lu) => lu,
+            Err(_) => return K::default(),
+        };
+        let mut determinant = K::one();
+        for (k, &p) in pivots.iter().enumerate() {
+            determinant = determinant * lu.get(k, k);
+            if p != k {
+                determinant = -determinant;
+            }
+        }
+        return determinant;
+    }
+
+    // Time: O(n * lower * (lower + upper)) − Space: O(n * (2 * lower + upper))
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>, &'static str>
+    where
+        K: Field,
+    {
+        let b = b.flat();
+        if b.len() != self.size {
+            return Err("The right-hand side does not match the matrix");
+        }
+        let (lu, pivots) = self.lu()?;
+
+        // Replay the swaps and eliminations on b, then U x = y
+        let mut x = b;
+        for k in 0..self.size {
+            x.swap(k, pivots[k]);
+            for i in k + 1..(k + lu.lower + 1).min(self.size) {
+                x[i] = x[i] - lu.get(i, k) * x[k];
+            }
+        }
+        for r in (0..self.size).rev() {
+            for c in r + 1..(r + lu.upper + 1).min(self.size) {
+                x[r] = x[r] - lu.get(r, c) * x[c];
+            }
+            x[r] = x[r] / lu.get(r, r);
+        }
+        return Ok(Vector::from(x));
+    }
+}
+
+// To dense
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > From<&Tridiagonal<K>> for Matrix<K>
+{
+    // Time: O(n^2) − Space: O(n^2)
+    fn from(t: &Tridiagonal<K>) -> Self {
+        let n = t.size();
+        let rows: Vec<Vec<K>> = (0..n).map(|r| (0..n).map(|c| t.get(r, c)).collect()).collect();
+        return Matrix::from(rows);
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > From<&Banded<K>> for Matrix<K>
+{
+    // Time: O(n^2) − Space: O(n^2)
+    fn from(b: &Banded<K>) -> Self {
+        let n = b.size;
+        let rows: Vec<Vec<K>> = (0..n).map(|r| (0..n).map(|c| b.get(r, c)).collect()).collect();
+        return Matrix::from(rows);
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > From<&Tridiagonal<K>> for Banded<K>
+{
+    // Time: O(n) − Space: O(n)
+    fn from(t: &Tridiagonal<K>) -> Self {
+        let n = t.size();
+        let mut banded: Banded<K> = Banded::new(n, 1, 1);
+        for r in 0..n {
+            for c in banded.band_columns(r) {
+                banded.set(r, c, t.get(r, c));
+            }
+        }
+        return banded;
+    }
+}
+
+// From dense, refused when a cell outside the structure is not zero
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > TryFrom<&Matrix<K>> for Tridiagonal<K>
+{
+    type Error = &'static str;
+
+    // Time: O(n^2) − Space: O(n)
+    fn try_from(m: &Matrix<K>) -> Result<Self, Self::Error> {
+        if !m.is_square() {
+            return Err("The matrix is not square");
+        }
+        let n = m.shape().0;
+        for r in 0..n {
+            for c in 0..n {
+                if r.abs_diff(c) > 1 && m.get(r, c) != K::default() {
+                    return Err("The matrix has non-zero cells outside its three diagonals");
+                }
+            }
+        }
+        return Ok(Tridiagonal::new(
+            (0..n - 1).map(|i| m.get(i + 1, i)).collect(),
+            (0..n).map(|i| m.get(i, i)).collect(),
+            (0..n - 1).map(|i| m.get(i, i + 1)).collect(),
+        ));
+    }
+}
+
+// From dense, with the narrowest band holding every non-zero cell
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > TryFrom<&Matrix<K>> for Banded<K>
+{
+    type Error = &'static str;
+
+    // Time: O(n^2) − Space: O(n * (lower + upper))
+    fn try_from(m: &Matrix<K>) -> Result<Self, Self::Error> {
+        if !m.is_square() {
+            return Err("The matrix is not square");
+        }
+        let n = m.shape().0;
+        let (mut lower, mut upper) = (0, 0);
+        for r in 0..n {
+            for c in 0..n {
+                if m.get(r, c) != K::default() {
+                    lower = lower.max(r.saturating_sub(c));
+                    upper = upper.max(c.saturating_sub(r));
+                }
+            }
+        }
+        let mut banded: Banded<K> = Banded::new(n, lower, upper);
+        for r in 0..n {
+            for c in banded.band_columns(r) {
+                banded.set(r, c, m.get(r, c));
+            }
+        }
+        return Ok(banded);
+    }
+}
+
+// print! and println!, as the dense matrix
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > fmt::Display for Tridiagonal<K>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Matrix::from(self))
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > fmt::Display for Banded<K>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Matrix::from(self))
+    }
+}
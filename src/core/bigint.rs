@@ -0,0 +1,489 @@
+use std::ops::{Add, Sub, Mul, Div, Rem, Neg};
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::core::rational::Rational;
+use crate::operations::{Integer, Operations};
+
+// Signed integer of arbitrary size: the limbs live on the heap and grow
+// as needed, so it is Clone but not Copy
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    negative: bool,
+    // Little-endian base 2^32 digits of the magnitude, without leading zeros
+    limbs: Vec<u32>,
+}
+
+// Exact fractions of big integers
+pub type BigRational = Rational<BigInt>;
+
+// Magnitudes are little-endian limbs without leading zeros
+
+fn trim(mut a: Vec<u32>) -> Vec<u32> {
+    while a.last() == Some(&0) {
+        a.pop();
+    }
+    return a;
+}
+
+fn compare(a: &[u32], b: &[u32]) -> Ordering {
+    if a.len() != b.len() {
+        return a.len().cmp(&b.len());
+    }
+    for i in (0..a.len()).rev() {
+        if a[i] != b[i] {
+            return a[i].cmp(&b[i]);
+        }
+    }
+    return Ordering::Equal;
+}
+
+fn add(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut sum: Vec<u32> = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry: u64 = 0;
+    for i in 0..a.len().max(b.len()) {
+        let s = *a.get(i).unwrap_or(&0) as u64 + *b.get(i).unwrap_or(&0) as u64 + carry;
+        sum.push(s as u32);
+        carry = s >> 32;
+    }
+    sum.push(carry as u32);
+    return trim(sum);
+}
+
+// a - b, with a >= b
+fn sub(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut difference: Vec<u32> = Vec::with_capacity(a.len());
+    let mut borrow: i64 = 0;
+    for i in 0..a.len() {
+        let d = a[i] as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+        difference.push(d as u32);
+        borrow = if d < 0 { 1 } else { 0 };
+    }
+    return trim(difference);
+}
+
+// Schoolbook multiplication
+fn mul(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut product: Vec<u32> = vec![0; a.len() + b.len()];
+    for i in 0..a.len() {
+        let mut carry: u64 = 0;
+        for j in 0..b.len() {
+            let p = a[i] as u64 * b[j] as u64 + product[i + j] as u64 + carry;
+            product[i + j] = p as u32;
+            carry = p >> 32;
+        }
+        product[i + b.len()] = carry as u32;
+    }
+    return trim(product);
+}
+
+// (quotient, remainder) by a single limb
+fn divide_small(a: &[u32], b: u32) -> (Vec<u32>, u32) {
+    let mut quotient: Vec<u32> = vec![0; a.len()];
+    let mut remainder: u64 = 0;
+    for i in (0..a.len()).rev() {
+        let current = (remainder << 32) | a[i] as u64;
+        quotient[i] = (current / b as u64) as u32;
+        remainder = current % b as u64;
+    }
+    return (trim(quotient), remainder as u32);
+}
+
+// (quotient, remainder), Knuth's algorithm D. b must not be zero.
+fn divide(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if compare(a, b) == Ordering::Less {
+        return (Vec::new(), a.to_vec());
+    }
+    if b.len() == 1 {
+        let (quotient, remainder) = divide_small(a, b[0]);
+        return (quotient, trim(vec![remainder]));
+    }
+
+    // Normalise so that the top limb of the divisor has its high bit set
+    let (m, n) = (a.len(), b.len());
+    let shift = b[n - 1].leading_zeros();
+    let shl = |x: &[u32], extra: usize| -> Vec<u32> {
+        let mut y: Vec<u32> = vec![0; x.len() + extra];
+        for i in 0..x.len() {
+            y[i] |= x[i] << shift;
+            if shift > 0 && i + 1 < y.len() {
+                y[i + 1] |= x[i] >> (32 - shift);
+            }
+        }
+        return y;
+    };
+    let v = shl(b, 0);
+    let mut u = shl(a, 1);
+    let mut quotient: Vec<u32> = vec![0; m - n + 1];
+    let base: u64 = 1 << 32;
+
+    for j in (0..=m - n).rev() {
+        // Estimate the quotient digit from the top two limbs, then correct it
+        let top = ((u[j + n] as u64) << 32) | u[j + n - 1] as u64;
+        let mut q = top / v[n - 1] as u64;
+        let mut r = top % v[n - 1] as u64;
+        while q >= base || q * v[n - 2] as u64 > ((r << 32) | u[j + n - 2] as u64) {
+            q -= 1;
+            r += v[n - 1] as u64;
+            if r >= base {
+                break;
+            }
+        }
+
+        // u[j..j + n] -= q * v
+        let mut borrow: i64 = 0;
+        for i in 0..n {
+            let p = q * v[i] as u64;
+            let t = u[i + j] as i64 - borrow - (p & 0xFFFF_FFFF) as i64;
+            u[i + j] = t as u32;
+            borrow = (p >> 32) as i64 - (t >> 32);
+        }
+        let t = u[j + n] as i64 - borrow;
+        u[j + n] = t as u32;
+
+        // The estimate was one too large: add v back
+        if t < 0 {
+            q -= 1;
+            let mut carry: u64 = 0;
+            for i in 0..n {
+                let s = u[i + j] as u64 + v[i] as u64 + carry;
+                u[i + j] = s as u32;
+                carry = s >> 32;
+            }
+            u[j + n] = u[j + n].wrapping_add(carry as u32);
+        }
+        quotient[j] = q as u32;
+    }
+
+    let mut remainder: Vec<u32> = vec![0; n];
+    for i in 0..n {
+        remainder[i] = u[i] >> shift;
+        if shift > 0 {
+            remainder[i] |= u[i + 1] << (32 - shift);
+        }
+    }
+    return (trim(quotient), trim(remainder));
+}
+
+fn shift_right(a: &mut Vec<u32>, bits: usize) {
+    let (limbs, bits) = (bits / 32, (bits % 32) as u32);
+    a.drain(..limbs.min(a.len()));
+    if bits > 0 {
+        for i in 0..a.len() {
+            a[i] >>= bits;
+            if i + 1 < a.len() {
+                a[i] |= a[i + 1] << (32 - bits);
+            }
+        }
+    }
+    while a.last() == Some(&0) {
+        a.pop();
+    }
+}
+
+fn trailing_zeros(a: &[u32]) -> usize {
+    let mut zeros = 0;
+    for &limb in a {
+        if limb != 0 {
+            return zeros + limb.trailing_zeros() as usize;
+        }
+        zeros += 32;
+    }
+    return zeros;
+}
+
+// Binary GCD, only shifts and subtractions in place
+fn gcd(mut a: Vec<u32>, mut b: Vec<u32>) -> Vec<u32> {
+    if a.is_empty() {
+        return b;
+    }
+    if b.is_empty() {
+        return a;
+    }
+    let (za, zb) = (trailing_zeros(&a), trailing_zeros(&b));
+    shift_right(&mut a, za);
+    shift_right(&mut b, zb);
+
+    // Both odd from here on
+    loop {
+        match compare(&a, &b) {
+            Ordering::Equal => break,
+            Ordering::Less => std::mem::swap(&mut a, &mut b),
+            Ordering::Greater => {}
+        }
+        let mut borrow: i64 = 0;
+        for i in 0..a.len() {
+            let d = a[i] as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+            a[i] = d as u32;
+            borrow = if d < 0 { 1 } else { 0 };
+        }
+        let zeros = trailing_zeros(&a);
+        shift_right(&mut a, zeros);
+    }
+
+    let mut shifted = vec![0; za.min(zb) / 32];
+    shifted.extend(a);
+    return mul(&shifted, &[1 << (za.min(zb) % 32)]);
+}
+
+impl BigInt {
+    fn from_magnitude(negative: bool, magnitude: Vec<u32>) -> BigInt {
+        return BigInt {
+            negative: negative && !magnitude.is_empty(),
+            limbs: magnitude,
+        };
+    }
+
+    pub fn is_negative(&self) -> bool {
+        return self.negative;
+    }
+
+    // Number of bits of the magnitude, 0 for zero
+    pub fn bits(&self) -> usize {
+        return match self.limbs.last() {
+            Some(top) => 32 * self.limbs.len() - top.leading_zeros() as usize,
+            None => 0,
+        };
+    }
+
+    pub fn pow(&self, exponent: u32) -> BigInt {
+        let mut result = BigInt::from(1);
+        let mut base = self.clone();
+        let mut exponent = exponent;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base.clone();
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = base.clone() * base;
+            }
+        }
+        return result;
+    }
+}
+
+impl From<i32> for BigInt {
+    fn from(value: i32) -> BigInt {
+        return BigInt::from(value as i128);
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(value: i64) -> BigInt {
+        return BigInt::from(value as i128);
+    }
+}
+
+impl From<i128> for BigInt {
+    fn from(value: i128) -> BigInt {
+        let mut magnitude = value.unsigned_abs();
+        let mut limbs: Vec<u32> = Vec::new();
+        while magnitude > 0 {
+            limbs.push(magnitude as u32);
+            magnitude >>= 32;
+        }
+        return BigInt::from_magnitude(value < 0, limbs);
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<BigInt, String> {
+        let trimmed = s.trim();
+        let (negative, digits) = match trimmed.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, trimmed.strip_prefix('+').unwrap_or(trimmed)),
+        };
+        if digits.is_empty() || !digits.bytes().all(|d| d.is_ascii_digit()) {
+            return Err(format!("Invalid integer '{}'", trimmed));
+        }
+
+        // Nine decimal digits at a time
+        let mut magnitude: Vec<u32> = Vec::new();
+        let bytes = digits.as_bytes();
+        let mut start = bytes.len() % 9;
+        if start == 0 {
+            start = 9;
+        }
+        let mut chunk = &digits[..start];
+        let mut at = start;
+        loop {
+            let value: u32 = chunk.parse().unwrap();
+            magnitude = add(&mul(&magnitude, &[1_000_000_000]), &trim(vec![value]));
+            if at == bytes.len() {
+                break;
+            }
+            chunk = &digits[at..at + 9];
+            at += 9;
+        }
+        return Ok(BigInt::from_magnitude(negative, magnitude));
+    }
+}
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.limbs.is_empty() {
+            return write!(f, "0");
+        }
+        // Nine decimal digits at a time, least significant first
+        let mut chunks: Vec<u32> = Vec::new();
+        let mut magnitude = self.limbs.clone();
+        while !magnitude.is_empty() {
+            let (quotient, remainder) = divide_small(&magnitude, 1_000_000_000);
+            chunks.push(remainder);
+            magnitude = quotient;
+        }
+        let mut s = String::new();
+        if self.negative {
+            s.push('-');
+        }
+        s.push_str(&chunks[chunks.len() - 1].to_string());
+        for chunk in chunks.iter().rev().skip(1) {
+            s.push_str(&format!("{:09}", chunk));
+        }
+        return write!(f, "{}", s);
+    }
+}
+
+impl Default for BigInt {
+    fn default() -> BigInt {
+        return BigInt { negative: false, limbs: Vec::new() };
+    }
+}
+
+impl Add for BigInt {
+    type Output = BigInt;
+
+    fn add(self, b: BigInt) -> BigInt {
+        if self.negative == b.negative {
+            return BigInt::from_magnitude(self.negative, add(&self.limbs, &b.limbs));
+        }
+        return match compare(&self.limbs, &b.limbs) {
+            Ordering::Less => BigInt::from_magnitude(b.negative, sub(&b.limbs, &self.limbs)),
+            _ => BigInt::from_magnitude(self.negative, sub(&self.limbs, &b.limbs)),
+        };
+    }
+}
+
+impl Sub for BigInt {
+    type Output = BigInt;
+
+    fn sub(self, b: BigInt) -> BigInt {
+        return self + -b;
+    }
+}
+
+impl Mul for BigInt {
+    type Output = BigInt;
+
+    fn mul(self, b: BigInt) -> BigInt {
+        return BigInt::from_magnitude(self.negative != b.negative, mul(&self.limbs, &b.limbs));
+    }
+}
+
+// Truncates toward zero, as the primitive integers
+impl Div for BigInt {
+    type Output = BigInt;
+
+    fn div(self, b: BigInt) -> BigInt {
+        if b.limbs.is_empty() {
+            panic!("Division of a BigInt by zero");
+        }
+        let (quotient, _) = divide(&self.limbs, &b.limbs);
+        return BigInt::from_magnitude(self.negative != b.negative, quotient);
+    }
+}
+
+// Has the sign of the dividend, as the primitive integers
+impl Rem for BigInt {
+    type Output = BigInt;
+
+    fn rem(self, b: BigInt) -> BigInt {
+        if b.limbs.is_empty() {
+            panic!("Division of a BigInt by zero");
+        }
+        let (_, remainder) = divide(&self.limbs, &b.limbs);
+        return BigInt::from_magnitude(self.negative, remainder);
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        return BigInt::from_magnitude(!self.negative, self.limbs);
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, b: &BigInt) -> Option<Ordering> {
+        return Some(self.cmp(b));
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, b: &BigInt) -> Ordering {
+        return match (self.negative, b.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => compare(&self.limbs, &b.limbs),
+            (true, true) => compare(&b.limbs, &self.limbs),
+        };
+    }
+}
+
+impl Operations for BigInt {
+    fn abs(&self) -> Self {
+        return BigInt::from_magnitude(false, self.limbs.clone());
+    }
+
+    // Floor of the square root, by Newton's method
+    fn sqrt(&self) -> Self {
+        if self.negative {
+            panic!("The square root of a negative BigInt is not supported");
+        }
+        if self.limbs.is_empty() {
+            return self.clone();
+        }
+        let two = BigInt::from(2);
+        let mut x = two.pow(self.bits().div_ceil(2) as u32);
+        loop {
+            let y = (x.clone() + self.clone() / x.clone()) / two.clone();
+            if y >= x {
+                return x;
+            }
+            x = y;
+        }
+    }
+
+    fn one() -> Self {
+        return BigInt::from(1);
+    }
+
+    fn is_zero(&self) -> bool {
+        return self.limbs.is_empty();
+    }
+}
+
+impl Integer for BigInt {
+    fn gcd(a: Self, b: Self) -> Self {
+        return BigInt::from_magnitude(false, gcd(a.limbs, b.limbs));
+    }
+
+    fn to_f64(self) -> f64 {
+        let mut value = 0.;
+        for &limb in self.limbs.iter().rev() {
+            value = value * 4294967296. + limb as f64;
+        }
+        if self.negative {
+            return -value;
+        }
+        return value;
+    }
+}
@@ -0,0 +1,99 @@
+use crate::core::matrix::Matrix;
+use crate::operations::{truncating_division, Operations};
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > Matrix<K>
+{
+    // Copy without row i and column j
+    fn without(&self, i: usize, j: usize) -> Matrix<K> {
+        let n = self.shape().0;
+        let rows: Vec<Vec<K>> = (0..n)
+            .filter(|&r| r != i)
+            .map(|r| (0..n).filter(|&c| c != j).map(|c| self.get(r, c)).collect())
+            .collect();
+        return Matrix::from(rows);
+    }
+
+    // Determinant of the matrix without row i and column j.
+    // Fraction-free, so exact for integers too
+    // Time: O(n^3) − Space: O(n^2)
+    pub fn minor(&self, i: usize, j: usize) -> K {
+        if !self.is_square() {
+            panic!("Shape {:?} is not a square", self.shape());
+        }
+        let n = self.shape().0;
+        if i >= n || j >= n {
+            panic!("Cell ({}, {}) is out of bounds for shape {:?}", i, j, self.shape());
+        }
+        // The empty matrix has determinant 1
+        if n == 1 {
+            return K::one();
+        }
+        return self.without(i, j).determinant();
+    }
+
+    // (-1)^(i + j) * minor(i, j)
+    // Time: O(n^3) − Space: O(n^2)
+    pub fn cofactor(&self, i: usize, j: usize) -> K {
+        let minor = self.minor(i, j);
+        return if (i + j).is_multiple_of(2) { minor } else { -minor };
+    }
+
+    // One minor per cell, so that it also works for singular matrices
+    // and scalars without division
+    // Time: O(n^5) − Space: O(n^2)
+    pub fn cofactor_matrix(&self) -> Matrix<K> {
+        let n = self.shape().0;
+        let mut cofactors = self.clone();
+        for i in 0..n {
+            for j in 0..n {
+                cofactors.set(i, j, self.cofactor(i, j));
+            }
+        }
+        return cofactors;
+    }
+
+    // Transposed cofactor matrix, self * adjugate == det(self) * I
+    // Time: O(n^5) − Space: O(n^2)
+    pub fn adjugate(&self) -> Matrix<K> {
+        let mut adjugate = self.cofactor_matrix();
+        adjugate.transpose();
+        return adjugate;
+    }
+
+    // adjugate / det. When the division truncates, every quotient is
+    // checked to be exact: integer matrices are only invertible when
+    // det(self) is a unit (±1). Fields, floating points included, divide.
+    // Time: O(n^5) − Space: O(n^2)
+    pub fn adjugate_inverse(&self) -> Result<Matrix<K>, &'static str> {
+        let determinant = self.determinant();
+        if determinant == K::default() {
+            return Err("The matrix is singular");
+        }
+        let truncating = truncating_division::<K>();
+        let mut inverse = self.adjugate();
+        let n = self.shape().0;
+        for i in 0..n {
+            for j in 0..n {
+                let cell = inverse.get(i, j);
+                let quotient = cell / determinant;
+                if truncating && quotient * determinant != cell {
+                    return Err("The inverse does not exist over these scalars");
+                }
+                inverse.set(i, j, quotient);
+            }
+        }
+        return Ok(inverse);
+    }
+}
@@ -1,7 +1,7 @@
 use std::ops::{Add, Sub, Mul, Div};
 use std::fmt::Display;
 
-use crate::operations::Operations;
+use crate::operations::{Field, Operations};
 
 #[derive(Debug, Copy, Clone)]
 pub struct Complex {
@@ -13,6 +13,14 @@ impl Complex {
     pub fn new(r: f32, i: f32) -> Complex {
         return Complex { r, i };
     }
+
+    pub fn real(&self) -> f32 {
+        return self.r;
+    }
+
+    pub fn imaginary(&self) -> f32 {
+        return self.i;
+    }
 }
 
 impl From<(f32, f32)> for Complex {
@@ -39,8 +47,18 @@ impl Operations for Complex {
             i: 0.,
         };
     }
+
+    fn one() -> Self {
+        return Complex { r: 1., i: 0. };
+    }
+
+    fn is_zero(&self) -> bool {
+        return self.r.is_zero() && self.i.is_zero();
+    }
 }
 
+impl Field for Complex {}
+
 impl Display for Complex {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         return write!(f, "({} + {}i)", self.r, self.i);
@@ -0,0 +1,53 @@
+use crate::core::matrix::Matrix;
+use crate::core::vector::Vector;
+use crate::operations::{truncating_division, Operations};
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > Matrix<K>
+{
+    // x_i = det(self with column i replaced by b) / det(self).
+    // When the division truncates, every quotient is checked to be exact,
+    // so integer systems only succeed when their solution is integral.
+    // Time: O(n^4) − Space: O(n^2)
+    pub fn solve_cramer(&self, b: &Vector<K>) -> Result<Vector<K>, &'static str> {
+        if !self.is_square() {
+            panic!("Shape {:?} is not a square", self.shape());
+        }
+        let n = self.shape().0;
+        if b.shape().0 != n {
+            return Err("The right-hand side does not match the matrix");
+        }
+        let determinant = self.determinant();
+        if determinant == K::default() {
+            return Err("The matrix is singular");
+        }
+
+        let truncating = truncating_division::<K>();
+        let b = b.flat();
+        let mut x: Vec<K> = Vec::new();
+        for i in 0..n {
+            let mut replaced = self.clone();
+            for r in 0..n {
+                replaced.set(r, i, b[r]);
+            }
+            let numerator = replaced.determinant();
+            let quotient = numerator / determinant;
+            if truncating && quotient * determinant != numerator {
+                return Err("The solution does not exist over these scalars");
+            }
+            x.push(quotient);
+        }
+        return Ok(Vector::from(x));
+    }
+}
@@ -0,0 +1,402 @@
+use crate::core::matrix::Matrix;
+use crate::core::vector::Vector;
+use crate::core::trace::RowOperation;
+use crate::operations::{Field, Operations};
+
+// Result of a row reduction
+pub struct Echelon<K: std::fmt::Display> {
+    pub matrix: Matrix<K>,
+    // (row, column) of each pivot, from top to bottom
+    pub pivots: Vec<(usize, usize)>,
+    // Columns without a pivot, i.e. the free variables
+    pub free_columns: Vec<usize>,
+    // permutation[i] is the row of the original matrix
+    // that ended up in row i
+    pub permutation: Vec<usize>,
+    // Product of the determinants of the row operations applied,
+    // so that det(matrix) == scale * det(original)
+    pub scale: K,
+}
+
+// Result of a fraction-free (Bareiss) elimination: every division is exact,
+// so integer matrices stay integer. Each pivot is a leading minor of
+// the permuted matrix, the last one of a square matrix is ±det.
+pub struct Bareiss<K: std::fmt::Display> {
+    pub matrix: Matrix<K>,
+    // (row, column) of each pivot, from top to bottom
+    pub pivots: Vec<(usize, usize)>,
+    // Columns without a pivot, i.e. the free variables
+    pub free_columns: Vec<usize>,
+    // permutation[i] is the row of the original matrix
+    // that ended up in row i
+    pub permutation: Vec<usize>,
+    // Sign of the permutation
+    pub sign: K,
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Clone
+            + Operations
+    > Echelon<K>
+{
+    pub fn rank(&self) -> usize {
+        return self.pivots.len();
+    }
+
+    pub fn pivot_columns(&self) -> Vec<usize> {
+        return self.pivots.iter().map(|&(_, c)| c).collect();
+    }
+
+    // Determinant of the original matrix, from the diagonal of the echelon form
+    // Time: O(n) − Space: O(1)
+    pub fn determinant(&self) -> Result<K, &'static str> {
+        if !self.matrix.is_square() {
+            return Err("The matrix is not square");
+        }
+        if self.rank() < self.matrix.shape().0 {
+            return Ok(K::default());
+        }
+        let mut product = K::one();
+        for i in 0..self.matrix.shape().0 {
+            product = product * self.matrix.get(i, i);
+        }
+        return Ok(product / self.scale.clone());
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Clone
+            + Operations
+    > Bareiss<K>
+{
+    pub fn rank(&self) -> usize {
+        return self.pivots.len();
+    }
+
+    pub fn pivot_columns(&self) -> Vec<usize> {
+        return self.pivots.iter().map(|&(_, c)| c).collect();
+    }
+
+    // Time: O(1) − Space: O(1)
+    pub fn determinant(&self) -> Result<K, &'static str> {
+        if !self.matrix.is_square() {
+            return Err("The matrix is not square");
+        }
+        let n = self.matrix.shape().0;
+        if n == 0 {
+            return Ok(K::one());
+        }
+        if self.rank() < n {
+            return Ok(K::default());
+        }
+        return Ok(self.sign.clone() * self.matrix.get(n - 1, n - 1));
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Clone
+            + Operations
+    > Matrix<K>
+{
+    // Fraction-free Gaussian elimination (Bareiss), for any integral domain:
+    // row r <- (pivot * row r - cell * pivot row) / previous pivot
+    // Time: O(m^2 * n) − Space: O(mn)
+    // where self is a matrix of shape (m, n)
+    pub fn bareiss(&self) -> Bareiss<K> {
+        let mut matrix: Matrix<K> = self.clone();
+        let (rows, columns) = matrix.shape();
+        let mut pivots: Vec<(usize, usize)> = Vec::new();
+        let mut free_columns: Vec<usize> = Vec::new();
+        let mut permutation: Vec<usize> = (0..rows).collect();
+        let mut sign: K = K::one();
+        let mut previous: K = K::one();
+
+        let mut curr: usize = 0;
+        for pvt_column in 0..columns {
+            if curr == rows {
+                free_columns.push(pvt_column);
+                continue;
+            }
+
+            // Largest cell (in absolute value) of the column,
+            // on or below the current row. Every cell is a minor, so only
+            // an exact zero is no pivot: a tiny float minor is still one
+            let mut pvt_row: Option<usize> = None;
+            for r in curr..rows {
+                let cell = matrix.get(r, pvt_column);
+                if cell == K::default() {
+                    continue;
+                }
+                match pvt_row {
+                    Some(p) if cell.abs() <= matrix.get(p, pvt_column).abs() => {}
+                    _ => pvt_row = Some(r),
+                }
+            }
+
+            let pvt_row = match pvt_row {
+                Some(r) => r,
+                None => {
+                    free_columns.push(pvt_column);
+                    continue;
+                }
+            };
+
+            if pvt_row != curr {
+                RowOperation::Swap { a: curr, b: pvt_row }.apply(&mut matrix);
+                permutation.swap(curr, pvt_row);
+                sign = -sign;
+            }
+
+            let pivot = matrix.get(curr, pvt_column);
+            for r in curr + 1..rows {
+                let cell = matrix.get(r, pvt_column);
+                for c in pvt_column + 1..columns {
+                    let value = K::cross_quotient(
+                        pivot.clone(),
+                        matrix.get(r, c),
+                        cell.clone(),
+                        matrix.get(curr, c),
+                        previous.clone(),
+                    );
+                    matrix.set(r, c, value);
+                }
+                matrix.set(r, pvt_column, K::default());
+            }
+
+            previous = pivot;
+            pivots.push((curr, pvt_column));
+            curr += 1;
+        }
+
+        return Bareiss {
+            matrix,
+            pivots,
+            free_columns,
+            permutation,
+            sign,
+        };
+    }
+
+    // Rank, nullity and inverse come from one elimination and one zero test:
+    // the fraction-free one for exact scalars, integers included, and the
+    // reduced echelon form with its residue thresholds for floating points
+    // Time: O(m^2 * n) − Space: O(mn)
+    pub fn rank(&self) -> usize {
+        if K::epsilon() == K::default() {
+            return self.bareiss().rank();
+        }
+        return self.echelon(true).rank();
+    }
+
+    // Per column, the largest cell that is only rounding residue of the
+    // elimination: epsilon * max |cell of the column| * m, so that the
+    // columns of a matrix keep their threshold once it is augmented.
+    // Zero for exact scalars, so that only exact zeros are skipped.
+    fn residue_thresholds(&self) -> Vec<K> {
+        let (rows, columns) = self.shape();
+        let mut size = K::default();
+        for _ in 0..rows {
+            size = size + K::one();
+        }
+
+        let mut thresholds: Vec<K> = Vec::new();
+        for c in 0..columns {
+            let mut largest = K::default();
+            for r in 0..rows {
+                let cell = self.get(r, c).abs();
+                if cell > largest {
+                    largest = cell;
+                }
+            }
+            thresholds.push(K::epsilon() * largest * size.clone());
+        }
+        return thresholds;
+    }
+
+    // Divides by its pivots: only called on fields, or on floating points
+    fn echelon(&self, reduced: bool) -> Echelon<K> {
+        let thresholds = self.residue_thresholds();
+        let mut matrix: Matrix<K> = self.clone();
+        let (rows, columns) = matrix.shape();
+        let mut pivots: Vec<(usize, usize)> = Vec::new();
+        let mut free_columns: Vec<usize> = Vec::new();
+        let mut permutation: Vec<usize> = (0..rows).collect();
+        let mut scale: K = K::one();
+
+        let mut curr: usize = 0;
+        for pvt_column in 0..columns {
+            if curr == rows {
+                free_columns.push(pvt_column);
+                continue;
+            }
+
+            // Largest cell (in absolute value) of the column,
+            // on or below the current row
+            let mut pvt_row: Option<usize> = None;
+            for r in curr..rows {
+                let cell = matrix.get(r, pvt_column);
+                if is_residue(&cell, &thresholds[pvt_column]) {
+                    matrix.set(r, pvt_column, K::default());
+                    continue;
+                }
+                match pvt_row {
+                    Some(p) if cell.abs() <= matrix.get(p, pvt_column).abs() => {}
+                    _ => pvt_row = Some(r),
+                }
+            }
+
+            let pvt_row = match pvt_row {
+                Some(r) => r,
+                None => {
+                    free_columns.push(pvt_column);
+                    continue;
+                }
+            };
+
+            if pvt_row != curr {
+                RowOperation::Swap { a: curr, b: pvt_row }.apply(&mut matrix);
+                permutation.swap(curr, pvt_row);
+                scale = -scale;
+            }
+
+            if reduced {
+                let factor = K::one() / matrix.get(curr, pvt_column);
+                RowOperation::Scale { row: curr, factor: factor.clone() }.apply(&mut matrix);
+                matrix.set(curr, pvt_column, K::one());
+                scale = scale * factor;
+            }
+
+            let pivot = matrix.get(curr, pvt_column);
+            let targets: Vec<usize> = if reduced {
+                (0..rows).filter(|&r| r != curr).collect()
+            } else {
+                (curr + 1..rows).collect()
+            };
+            for r in targets {
+                let cell = matrix.get(r, pvt_column);
+                if is_residue(&cell, &thresholds[pvt_column]) {
+                    matrix.set(r, pvt_column, K::default());
+                    continue;
+                }
+                let factor = cell / pivot.clone();
+                RowOperation::Eliminate { target: r, source: curr, factor }.apply(&mut matrix);
+                // Avoid leaving rounding residue under/above the pivot
+                matrix.set(r, pvt_column, K::default());
+            }
+
+            pivots.push((curr, pvt_column));
+            curr += 1;
+        }
+
+        return Echelon {
+            matrix,
+            pivots,
+            free_columns,
+            permutation,
+            scale,
+        };
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Clone
+            + Operations
+            + Field
+    > Matrix<K>
+{
+    // Gaussian elimination with partial pivoting:
+    // pivots are not normalised and only the cells below them are zeroed
+    // Time: O(m^2 * n) − Space: O(mn)
+    // where self is a matrix of shape (m, n)
+    pub fn row_echelon_form(&self) -> Echelon<K> {
+        return self.echelon(false);
+    }
+
+    // Gauss-Jordan elimination with partial pivoting:
+    // pivots are 1 and the only non-zero cells of their column
+    // Time: O(m^2 * n) − Space: O(mn)
+    // where self is a matrix of shape (m, n)
+    pub fn reduced_row_echelon_form(&self) -> Echelon<K> {
+        return self.echelon(true);
+    }
+
+    // One solution of self * x = b, with the free variables set to zero
+    // Time: O(m^2 * n) − Space: O(mn)
+    // where self is a matrix of shape (m, n)
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>, &'static str> {
+        let (rows, columns) = self.shape();
+        if b.shape().0 != rows {
+            return Err("The right-hand side does not match the matrix");
+        }
+
+        let b = b.flat();
+        let mut augmented: Matrix<K> = Matrix::from(vec![vec![K::default(); columns + 1]; rows]);
+        for r in 0..rows {
+            for c in 0..columns {
+                augmented.set(r, c, self.get(r, c));
+            }
+            augmented.set(r, columns, b[r].clone());
+        }
+
+        let rref = augmented.reduced_row_echelon_form();
+        let mut x: Vec<K> = vec![K::default(); columns];
+        for &(r, c) in &rref.pivots {
+            // A pivot in the last column reads 0 = 1
+            if c == columns {
+                return Err("The system has no solution");
+            }
+            x[c] = rref.matrix.get(r, columns);
+        }
+        return Ok(Vector::from(x));
+    }
+
+}
+
+// Exactly zero for exact scalars, within the threshold for floating points
+fn is_residue<
+    K: std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + Default
+        + Operations
+>(cell: &K, threshold: &K) -> bool {
+    if *threshold == K::default() {
+        return *cell == K::default();
+    }
+    return cell.abs() <= *threshold;
+}
@@ -0,0 +1,211 @@
+use crate::core::matrix::Matrix;
+use crate::operations::{Field, Operations};
+
+// Denman-Beavers and the logarithm series stop after this many steps
+const MAX_ITERATIONS: usize = 100;
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+            + Field
+    > Matrix<K>
+{
+    fn identity_like(&self) -> Matrix<K> {
+        let n = self.shape().0;
+        let mut identity: Matrix<K> = Matrix::from(vec![vec![K::default(); n]; n]);
+        for i in 0..n {
+            identity.set(i, i, K::one());
+        }
+        return identity;
+    }
+
+    fn assert_square(&self) {
+        if !self.is_square() {
+            panic!("Shape {:?} is not a square", self.shape());
+        }
+    }
+
+    // Maximum absolute column sum
+    // Time: O(n^2) − Space: O(1)
+    fn norm_one(&self) -> K {
+        let (rows, columns) = self.shape();
+        let mut norm = K::default();
+        for c in 0..columns {
+            let mut sum = K::default();
+            for r in 0..rows {
+                sum = sum + self.get(r, c).abs();
+            }
+            if sum > norm {
+                norm = sum;
+            }
+        }
+        return norm;
+    }
+
+    // self^exponent by repeated squaring, through the inverse when negative
+    // Time: O(n^3 * log |e|) − Space: O(n^2)
+    pub fn pow(&self, exponent: i64) -> Result<Matrix<K>, &'static str> {
+        self.assert_square();
+        let mut base = if exponent < 0 { self.inverse()? } else { self.clone() };
+        let mut exponent = exponent.unsigned_abs();
+        let mut result = self.identity_like();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.mul_mat(base.clone());
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = base.mul_mat(base.clone());
+            }
+        }
+        return Ok(result);
+    }
+
+    // e^self by scaling and squaring: e^A = (e^(A / 2^s))^(2^s) with
+    // ||A / 2^s|| <= 1/2, where the [6/6] Padé approximant is accurate
+    // to about 1e-16
+    // Time: O(n^3 * (6 + s)) − Space: O(n^2)
+    pub fn expm(&self) -> Result<Matrix<K>, &'static str> {
+        self.assert_square();
+        const DEGREE: usize = 6;
+        let one = K::one();
+        let two = one + one;
+        let half = one / two;
+
+        // An infinite or NaN norm would never be halved below 1/2,
+        // and it is the only kind whose product by zero is not zero
+        if self.norm_one() * K::default() != K::default() {
+            return Err("The matrix holds a non finite entry");
+        }
+        let mut scaled = self.clone();
+        let mut squarings = 0;
+        while scaled.norm_one() > half {
+            scaled = scaled * half;
+            squarings += 1;
+        }
+
+        // N = sum c_k A^k and D = sum (-1)^k c_k A^k,
+        // with c_k = c_(k-1) * (q - k + 1) / (k * (2q - k + 1))
+        let mut numerator = self.identity_like();
+        let mut denominator = self.identity_like();
+        let mut power = self.identity_like();
+        let mut c = one;
+        let mut q = K::default();
+        for _ in 0..DEGREE {
+            q = q + one;
+        }
+        let mut k_scalar = K::default();
+        for k in 1..=DEGREE {
+            k_scalar = k_scalar + one;
+            c = c * (q - k_scalar + one) / (k_scalar * (q + q - k_scalar + one));
+            power = power.mul_mat(scaled.clone());
+            numerator = numerator + power.clone() * c;
+            denominator = if k % 2 == 0 {
+                denominator + power.clone() * c
+            } else {
+                denominator - power.clone() * c
+            };
+        }
+
+        let inverse = match denominator.inverse() {
+            Ok(inverse) => inverse,
+            Err(_) => return Err("The Padé denominator is singular"),
+        };
+        let mut exponential = inverse.mul_mat(numerator);
+        for _ in 0..squarings {
+            exponential = exponential.mul_mat(exponential.clone());
+        }
+        return Ok(exponential);
+    }
+
+    // Principal square root by the Denman-Beavers iteration
+    // Y <- (Y + Z^-1) / 2, Z <- (Z + Y^-1) / 2 from Y = A, Z = I,
+    // with Y -> sqrt(A) and Z -> sqrt(A)^-1 quadratically. It needs
+    // no eigenvalue on the closed negative real axis.
+    // Time: O(n^3) per iteration − Space: O(n^2)
+    pub fn sqrtm(&self) -> Result<Matrix<K>, &'static str> {
+        self.assert_square();
+        let half = K::one() / (K::one() + K::one());
+        // Zero for exact scalars, which then need an exact fixed point
+        let tolerance = K::epsilon().sqrt();
+        let mut y = self.clone();
+        let mut z = self.identity_like();
+
+        for _ in 0..MAX_ITERATIONS {
+            let (y_inverse, z_inverse) = match (y.inverse(), z.inverse()) {
+                (Ok(y_inverse), Ok(z_inverse)) => (y_inverse, z_inverse),
+                _ => return Err("The square root iteration met a singular matrix"),
+            };
+            let next = (y.clone() + z_inverse) * half;
+            z = (z + y_inverse) * half;
+
+            // Convergence is quadratic: a relative change below sqrt(epsilon)
+            // leaves the new iterate with twice the digits, accurate to rounding
+            let norm = next.norm_one();
+            let change = (next.clone() - y).norm_one();
+            y = next;
+            if norm == K::default() || change / norm <= tolerance {
+                return Ok(y);
+            }
+        }
+        return Err("The square root iteration did not converge");
+    }
+
+    // Principal logarithm by inverse scaling and squaring:
+    // log A = 2^s log(A^(1 / 2^s)), square roots being taken until
+    // X = A^(1 / 2^s) - I is small enough for the series
+    // log(I + X) = X - X^2 / 2 + X^3 / 3 - ...
+    // Time: O(n^3 * (s * sqrtm + terms)) − Space: O(n^2)
+    pub fn logm(&self) -> Result<Matrix<K>, &'static str> {
+        self.assert_square();
+        if self.inverse().is_err() {
+            return Err("The logarithm of a singular matrix is not defined");
+        }
+        let one = K::one();
+        let quarter = one / (one + one + one + one);
+
+        let mut root = self.clone();
+        let mut scale = one;
+        let mut x = root.clone() - self.identity_like();
+        let mut roots = 0;
+        while x.norm_one() > quarter {
+            if roots == MAX_ITERATIONS {
+                return Err("The logarithm scaling did not converge");
+            }
+            root = root.sqrtm()?;
+            scale = scale + scale;
+            x = root.clone() - self.identity_like();
+            roots += 1;
+        }
+
+        let mut logarithm = x.clone();
+        let mut power = x.clone();
+        let mut k_scalar = one;
+        let mut converged = false;
+        for k in 2..=MAX_ITERATIONS {
+            k_scalar = k_scalar + one;
+            power = power.mul_mat(x.clone());
+            let term = power.clone() * (one / k_scalar);
+            // The term no longer changes the sum at working precision
+            let norm = logarithm.norm_one();
+            converged = norm + term.norm_one() == norm;
+            logarithm = if k % 2 == 0 { logarithm - term } else { logarithm + term };
+            if converged {
+                break;
+            }
+        }
+        if !converged {
+            return Err("The logarithm series did not converge");
+        }
+        return Ok(logarithm * scale);
+    }
+}
@@ -0,0 +1,277 @@
+use std::ops::{Add, Sub, Mul, Div, Neg};
+use std::fmt::Display;
+
+use crate::operations::{Field, Operations};
+
+const fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    let mut d = 2;
+    while d <= n / d {
+        if n.is_multiple_of(d) {
+            return false;
+        }
+        d += 1;
+    }
+    return true;
+}
+
+// Integers modulo the prime P, stored as their representative in [0, P)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Gf<const P: u64> {
+    value: u64,
+}
+
+impl<const P: u64> Gf<P> {
+    // Evaluated once per P, refuses to compile Gf<P> for a composite P
+    const PRIME: () = assert!(is_prime(P), "The modulus of Gf<P> must be a prime");
+
+    pub fn new(value: u64) -> Gf<P> {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::PRIME;
+        return Gf { value: value % P };
+    }
+
+    pub fn value(&self) -> u64 {
+        return self.value;
+    }
+
+    // Time: O(log e) − Space: O(1)
+    pub fn pow(&self, exponent: u64) -> Gf<P> {
+        let mut result = Gf::new(1);
+        let mut base = *self;
+        let mut exponent = exponent;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exponent >>= 1;
+        }
+        return result;
+    }
+
+    // Multiplicative inverse, a^(P - 2) by Fermat's little theorem
+    pub fn inverse(&self) -> Result<Gf<P>, &'static str> {
+        if self.value == 0 {
+            return Err("Zero has no inverse");
+        }
+        return Ok(self.pow(P - 2));
+    }
+
+    // Euler's criterion
+    pub fn is_square(&self) -> bool {
+        return P == 2 || self.value == 0 || self.pow((P - 1) / 2).value == 1;
+    }
+}
+
+impl<const P: u64> From<u64> for Gf<P> {
+    fn from(value: u64) -> Gf<P> {
+        return Gf::new(value);
+    }
+}
+
+impl<const P: u64> From<i64> for Gf<P> {
+    fn from(value: i64) -> Gf<P> {
+        return Gf::new(value.rem_euclid(P as i64) as u64);
+    }
+}
+
+impl<const P: u64> Operations for Gf<P> {
+    // There is no order compatible with the field: every element is its own size
+    fn abs(&self) -> Self {
+        return *self;
+    }
+
+    // Tonelli-Shanks, the smaller of the two roots
+    fn sqrt(&self) -> Self {
+        if !self.is_square() {
+            panic!("{} has no square root modulo {}", self.value, P);
+        }
+        if P == 2 || self.value == 0 {
+            return *self;
+        }
+
+        // P - 1 = q * 2^s with q odd
+        let (mut q, mut s) = (P - 1, 0);
+        while q.is_multiple_of(2) {
+            q /= 2;
+            s += 1;
+        }
+        let mut z: Gf<P> = Gf::new(2);
+        while z.is_square() {
+            z = z + Gf::new(1);
+        }
+
+        let mut m = s;
+        let mut c = z.pow(q);
+        let mut t = self.pow(q);
+        let mut root = self.pow(q.div_ceil(2));
+        while t.value != 1 {
+            // Least i such that t^(2^i) == 1
+            let mut i = 0;
+            let mut square = t;
+            while square.value != 1 {
+                square = square * square;
+                i += 1;
+            }
+            let b = c.pow(1 << (m - i - 1));
+            m = i;
+            c = b * b;
+            t = t * c;
+            root = root * b;
+        }
+
+        return if root.value <= P - root.value { root } else { -root };
+    }
+
+    fn one() -> Self {
+        return Gf::new(1);
+    }
+
+    fn is_zero(&self) -> bool {
+        return self.value == 0;
+    }
+}
+
+impl<const P: u64> Field for Gf<P> {}
+
+impl<const P: u64> Display for Gf<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        return write!(f, "{}", self.value);
+    }
+}
+
+impl<const P: u64> Default for Gf<P> {
+    fn default() -> Gf<P> {
+        return Gf::new(0);
+    }
+}
+
+impl<const P: u64> Add for Gf<P> {
+    type Output = Gf<P>;
+
+    fn add(self, b: Gf<P>) -> Gf<P> {
+        return Gf::new(((self.value as u128 + b.value as u128) % P as u128) as u64);
+    }
+}
+
+impl<const P: u64> Sub for Gf<P> {
+    type Output = Gf<P>;
+
+    fn sub(self, b: Gf<P>) -> Gf<P> {
+        return self + -b;
+    }
+}
+
+impl<const P: u64> Mul for Gf<P> {
+    type Output = Gf<P>;
+
+    fn mul(self, b: Gf<P>) -> Gf<P> {
+        return Gf::new(((self.value as u128 * b.value as u128) % P as u128) as u64);
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<const P: u64> Div for Gf<P> {
+    type Output = Gf<P>;
+
+    fn div(self, b: Gf<P>) -> Gf<P> {
+        match b.inverse() {
+            Ok(inverse) => self * inverse,
+            Err(_) => panic!("Division by zero modulo {}", P),
+        }
+    }
+}
+
+impl<const P: u64> Neg for Gf<P> {
+    type Output = Gf<P>;
+
+    fn neg(self) -> Gf<P> {
+        return Gf::new(P - self.value);
+    }
+}
+
+// The two-element field: addition is XOR, multiplication is AND
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Gf2(pub bool);
+
+impl From<u8> for Gf2 {
+    fn from(bit: u8) -> Gf2 {
+        return Gf2(bit & 1 == 1);
+    }
+}
+
+impl Operations for Gf2 {
+    fn abs(&self) -> Self {
+        return *self;
+    }
+
+    // Every element is its own square root
+    fn sqrt(&self) -> Self {
+        return *self;
+    }
+
+    fn one() -> Self {
+        return Gf2(true);
+    }
+
+    fn is_zero(&self) -> bool {
+        return !self.0;
+    }
+}
+
+impl Field for Gf2 {}
+
+impl Display for Gf2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        return write!(f, "{}", self.0 as u8);
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl Add for Gf2 {
+    type Output = Gf2;
+
+    fn add(self, b: Gf2) -> Gf2 {
+        return Gf2(self.0 ^ b.0);
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl Sub for Gf2 {
+    type Output = Gf2;
+
+    fn sub(self, b: Gf2) -> Gf2 {
+        return Gf2(self.0 ^ b.0);
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl Mul for Gf2 {
+    type Output = Gf2;
+
+    fn mul(self, b: Gf2) -> Gf2 {
+        return Gf2(self.0 & b.0);
+    }
+}
+
+impl Div for Gf2 {
+    type Output = Gf2;
+
+    fn div(self, b: Gf2) -> Gf2 {
+        if !b.0 {
+            panic!("Division by zero in GF(2)");
+        }
+        return self;
+    }
+}
+
+impl Neg for Gf2 {
+    type Output = Gf2;
+
+    fn neg(self) -> Gf2 {
+        return self;
+    }
+}
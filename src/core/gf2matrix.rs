@@ -0,0 +1,272 @@
+use std::fmt;
+use crate::core::gf::Gf2;
+use crate::core::matrix::Matrix;
+use crate::core::vector::Vector;
+
+// Matrix over GF(2) with every row packed in 64-bit words,
+// so that adding a row to another is a XOR of ceil(n / 64) words
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Gf2Matrix {
+    shape: (usize, usize),
+    // Words per row
+    words: usize,
+    // Row-major, bit c % 64 of word c / 64 holds column c
+    data: Vec<u64>,
+}
+
+impl Gf2Matrix {
+    pub fn new(shape: (usize, usize)) -> Gf2Matrix {
+        let words = shape.1.div_ceil(64);
+        return Gf2Matrix {
+            shape,
+            words,
+            data: vec![0; shape.0 * words],
+        };
+    }
+
+    pub fn identity(n: usize) -> Gf2Matrix {
+        let mut identity = Gf2Matrix::new((n, n));
+        for i in 0..n {
+            identity.set(i, i, Gf2(true));
+        }
+        return identity;
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        return self.shape;
+    }
+
+    pub fn get(&self, r: usize, c: usize) -> Gf2 {
+        return Gf2((self.data[r * self.words + c / 64] >> (c % 64)) & 1 == 1);
+    }
+
+    pub fn set(&mut self, r: usize, c: usize, value: Gf2) {
+        let word = &mut self.data[r * self.words + c / 64];
+        if value.0 {
+            *word |= 1 << (c % 64);
+        } else {
+            *word &= !(1 << (c % 64));
+        }
+    }
+
+    fn row(&self, r: usize) -> &[u64] {
+        return &self.data[r * self.words..(r + 1) * self.words];
+    }
+
+    // Time: O(n / 64) − Space: O(1)
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        for w in 0..self.words {
+            self.data.swap(a * self.words + w, b * self.words + w);
+        }
+    }
+
+    // Row target <- row target + row source
+    // Time: O(n / 64) − Space: O(1)
+    pub fn xor_rows(&mut self, target: usize, source: usize) {
+        for w in 0..self.words {
+            let bits = self.data[source * self.words + w];
+            self.data[target * self.words + w] ^= bits;
+        }
+    }
+
+    // Time: O(mn) − Space: O(mn)
+    pub fn transpose(&self) -> Gf2Matrix {
+        let mut transposed = Gf2Matrix::new((self.shape.1, self.shape.0));
+        for r in 0..self.shape.0 {
+            for c in 0..self.shape.1 {
+                if self.get(r, c).0 {
+                    transposed.set(c, r, Gf2(true));
+                }
+            }
+        }
+        return transposed;
+    }
+
+    // Each cell is the parity of the common bits of a row and the vector
+    // Time: O(mn / 64) − Space: O(m + n)
+    pub fn mul_vec(&self, v: &Vector<Gf2>) -> Vector<Gf2> {
+        if v.shape().0 != self.shape.1 {
+            panic!(
+                "Shapes {:?} and {:?} are incompatible",
+                self.shape,
+                v.shape()
+            );
+        }
+        let packed = Gf2Matrix::from(&Matrix::from((v.flat(), (1, self.shape.1))));
+        let result: Vec<Gf2> = (0..self.shape.0)
+            .map(|r| {
+                let ones: u32 = self
+                    .row(r)
+                    .iter()
+                    .zip(packed.row(0))
+                    .map(|(a, b)| (a & b).count_ones())
+                    .sum();
+                Gf2(ones % 2 == 1)
+            })
+            .collect();
+        return Vector::from(result);
+    }
+
+    // Sum of the rows of mat selected by the bits of each row of self
+    // Time: O(mnp / 64) − Space: O(mp)
+    pub fn mul_mat(&self, mat: &Gf2Matrix) -> Gf2Matrix {
+        if self.shape.1 != mat.shape.0 {
+            panic!(
+                "Shapes {:?} and {:?} are incompatible",
+                self.shape, mat.shape
+            );
+        }
+        let mut product = Gf2Matrix::new((self.shape.0, mat.shape.1));
+        for r in 0..self.shape.0 {
+            for k in 0..self.shape.1 {
+                if self.get(r, k).0 {
+                    for w in 0..product.words {
+                        product.data[r * product.words + w] ^= mat.data[k * mat.words + w];
+                    }
+                }
+            }
+        }
+        return product;
+    }
+
+    // [self | other]
+    fn augment(&self, other: &Gf2Matrix) -> Gf2Matrix {
+        let mut augmented = Gf2Matrix::new((self.shape.0, self.shape.1 + other.shape.1));
+        for r in 0..self.shape.0 {
+            for c in 0..self.shape.1 {
+                augmented.set(r, c, self.get(r, c));
+            }
+            for c in 0..other.shape.1 {
+                augmented.set(r, self.shape.1 + c, other.get(r, c));
+            }
+        }
+        return augmented;
+    }
+
+    // Gauss-Jordan elimination with row swaps and XORs only,
+    // returns the reduced matrix and the pivot column of each non-zero row
+    // Time: O(m * n^2 / 64) − Space: O(mn / 64)
+    pub fn reduced_row_echelon(&self) -> (Gf2Matrix, Vec<usize>) {
+        let mut matrix = self.clone();
+        let mut pivots: Vec<usize> = Vec::new();
+        let mut curr = 0;
+
+        for c in 0..self.shape.1 {
+            if curr == self.shape.0 {
+                break;
+            }
+            let pvt_row = match (curr..self.shape.0).find(|&r| matrix.get(r, c).0) {
+                Some(r) => r,
+                None => continue,
+            };
+            matrix.swap_rows(curr, pvt_row);
+            for r in 0..self.shape.0 {
+                if r != curr && matrix.get(r, c).0 {
+                    matrix.xor_rows(r, curr);
+                }
+            }
+            pivots.push(c);
+            curr += 1;
+        }
+
+        return (matrix, pivots);
+    }
+
+    pub fn rank(&self) -> usize {
+        return self.reduced_row_echelon().1.len();
+    }
+
+    // Time: O(n^3 / 64) − Space: O(n^2 / 64)
+    pub fn inverse(&self) -> Result<Gf2Matrix, &'static str> {
+        if self.shape.0 != self.shape.1 {
+            return Err("The matrix is not square");
+        }
+        let n = self.shape.0;
+        let (rref, pivots) = self.augment(&Gf2Matrix::identity(n)).reduced_row_echelon();
+        if pivots.len() < n || pivots[n - 1] != n - 1 {
+            return Err("The matrix is singular");
+        }
+
+        let mut inverse = Gf2Matrix::new((n, n));
+        for r in 0..n {
+            for c in 0..n {
+                inverse.set(r, c, rref.get(r, n + c));
+            }
+        }
+        return Ok(inverse);
+    }
+
+    // Basis of { x | self * x = 0 }, one vector per free column
+    // Time: O(m * n^2 / 64) − Space: O(n^2)
+    pub fn null_space(&self) -> Vec<Vector<Gf2>> {
+        let (rref, pivots) = self.reduced_row_echelon();
+        let mut basis: Vec<Vector<Gf2>> = Vec::new();
+        for free in (0..self.shape.1).filter(|c| !pivots.contains(c)) {
+            let mut x: Vec<Gf2> = vec![Gf2(false); self.shape.1];
+            x[free] = Gf2(true);
+            for (r, &c) in pivots.iter().enumerate() {
+                x[c] = rref.get(r, free);
+            }
+            basis.push(Vector::from(x));
+        }
+        return basis;
+    }
+
+    // One solution of self * x = b, with the free variables set to zero
+    // Time: O(m * n^2 / 64) − Space: O(mn / 64)
+    pub fn solve(&self, b: &Vector<Gf2>) -> Result<Vector<Gf2>, &'static str> {
+        if b.shape().0 != self.shape.0 {
+            return Err("The right-hand side does not match the matrix");
+        }
+        let column = Gf2Matrix::from(&Matrix::from((b.flat(), (self.shape.0, 1))));
+        let (rref, pivots) = self.augment(&column).reduced_row_echelon();
+
+        let mut x: Vec<Gf2> = vec![Gf2(false); self.shape.1];
+        for (r, &c) in pivots.iter().enumerate() {
+            // A pivot in the last column reads 0 = 1
+            if c == self.shape.1 {
+                return Err("The system has no solution");
+            }
+            x[c] = rref.get(r, self.shape.1);
+        }
+        return Ok(Vector::from(x));
+    }
+}
+
+impl From<&Matrix<Gf2>> for Gf2Matrix {
+    fn from(matrix: &Matrix<Gf2>) -> Gf2Matrix {
+        let mut packed = Gf2Matrix::new(matrix.shape());
+        for r in 0..matrix.shape().0 {
+            for c in 0..matrix.shape().1 {
+                packed.set(r, c, matrix.get(r, c));
+            }
+        }
+        return packed;
+    }
+}
+
+impl From<&Gf2Matrix> for Matrix<Gf2> {
+    fn from(packed: &Gf2Matrix) -> Matrix<Gf2> {
+        let mut matrix = Matrix::from(vec![vec![Gf2(false); packed.shape.1]; packed.shape.0]);
+        for r in 0..packed.shape.0 {
+            for c in 0..packed.shape.1 {
+                matrix.set(r, c, packed.get(r, c));
+            }
+        }
+        return matrix;
+    }
+}
+
+impl fmt::Display for Gf2Matrix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for r in 0..self.shape.0 {
+            for c in 0..self.shape.1 {
+                write!(f, "{}", self.get(r, c))?;
+            }
+            if r < self.shape.0 - 1 {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use crate::core::matrix::Matrix;
+use crate::core::vector::Vector;
+use crate::operations::Operations;
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > Matrix<K>
+{
+    // Block matrix whose block (i, j) is self[i][j] * other
+    // Time: O(mnpq) − Space: O(mnpq)
+    // where self is a matrix of shape (m, n) and other of shape (p, q)
+    pub fn kronecker(&self, other: &Matrix<K>) -> Matrix<K> {
+        let (m, n) = self.shape();
+        let (p, q) = other.shape();
+        let mut product: Matrix<K> = Matrix::from(vec![vec![K::default(); n * q]; m * p]);
+        for i in 0..m {
+            for j in 0..n {
+                let a = self.get(i, j);
+                for k in 0..p {
+                    for l in 0..q {
+                        product.set(i * p + k, j * q + l, a * other.get(k, l));
+                    }
+                }
+            }
+        }
+        return product;
+    }
+
+    // self ⊗ I + I ⊗ other, for square matrices. Its eigenvalues are
+    // the sums of an eigenvalue of self and one of other.
+    // Time: O(m^2 * n^2) − Space: O(m^2 * n^2)
+    pub fn kronecker_sum(&self, other: &Matrix<K>) -> Matrix<K> {
+        if !self.is_square() || !other.is_square() {
+            panic!(
+                "Shapes {:?} and {:?} are not both square",
+                self.shape(),
+                other.shape()
+            );
+        }
+        let (m, n) = (self.shape().0, other.shape().0);
+        let mut sum: Matrix<K> = Matrix::from(vec![vec![K::default(); m * n]; m * n]);
+        for i in 0..m {
+            for j in 0..m {
+                for k in 0..n {
+                    let cell = sum.get(i * n + k, j * n + k);
+                    sum.set(i * n + k, j * n + k, cell + self.get(i, j));
+                }
+            }
+            for k in 0..n {
+                for l in 0..n {
+                    let cell = sum.get(i * n + k, i * n + l);
+                    sum.set(i * n + k, i * n + l, cell + other.get(k, l));
+                }
+            }
+        }
+        return sum;
+    }
+
+    // Columns stacked on top of each other, so that
+    // vec(A X B) = (B^T ⊗ A) vec(X)
+    // Time: O(mn) − Space: O(mn)
+    pub fn vec(&self) -> Vector<K> {
+        let (rows, columns) = self.shape();
+        let mut stacked: Vec<K> = Vec::new();
+        for c in 0..columns {
+            for r in 0..rows {
+                stacked.push(self.get(r, c));
+            }
+        }
+        return Vector::from(stacked);
+    }
+
+    // Inverse of vec
+    pub fn unvec(v: &Vector<K>, shape: (usize, usize)) -> Matrix<K> {
+        return Matrix::from((v.flat(), shape));
+    }
+}
@@ -1,6 +1,7 @@
 use std::{fmt, ops};
 use crate::core::vector::Vector;
-use crate::operations::Operations;
+use crate::core::trace::{RowOperation, RowStep, RowTrace};
+use crate::operations::{Field, Operations};
 
 // Struct
 pub struct Matrix<K: std::fmt::Display> {
@@ -9,7 +10,7 @@ pub struct Matrix<K: std::fmt::Display> {
 }
 
 // From 2D
-impl<K: std::fmt::Display + Copy> From<Vec<Vec<K>>> for Matrix<K> {
+impl<K: std::fmt::Display + Clone> From<Vec<Vec<K>>> for Matrix<K> {
     fn from(data: Vec<Vec<K>>) -> Self {
         if data.len() * data[0].len() != data.iter().flatten().count() {
             panic!(
@@ -20,13 +21,13 @@ impl<K: std::fmt::Display + Copy> From<Vec<Vec<K>>> for Matrix<K> {
         }
         return Matrix {
             shape: (data.len(), data[0].len()),
-            data: data.iter().flatten().copied().collect(),
+            data: data.iter().flatten().cloned().collect(),
         };
     }
 }
 
 // From 1D
-impl<K: std::fmt::Display + Copy> From<(Vec<K>, (usize, usize))> for Matrix<K> {
+impl<K: std::fmt::Display + Clone> From<(Vec<K>, (usize, usize))> for Matrix<K> {
     fn from(data: (Vec<K>, (usize, usize))) -> Self {
         // Reads the data in column-major order
         if data.1 .0 * data.1 .1 != data.0.len() {
@@ -40,7 +41,7 @@ impl<K: std::fmt::Display + Copy> From<(Vec<K>, (usize, usize))> for Matrix<K> {
         let mut row_major = Vec::new();
         for r in 0..data.1 .0 {
             for c in 0..data.1 .1 {
-                row_major.push(data.0[c * data.1 .0 + r]);
+                row_major.push(data.0[c * data.1 .0 + r].clone());
             }
         }
 
@@ -52,7 +53,7 @@ impl<K: std::fmt::Display + Copy> From<(Vec<K>, (usize, usize))> for Matrix<K> {
 }
 
 // Clone
-impl<K: std::fmt::Display + Copy> Clone for Matrix<K> {
+impl<K: std::fmt::Display + Clone> Clone for Matrix<K> {
     fn clone(&self) -> Self {
         return Matrix {
             shape: self.shape,
@@ -62,7 +63,7 @@ impl<K: std::fmt::Display + Copy> Clone for Matrix<K> {
 }
 
 // Addition overload
-impl<K: std::fmt::Display + ops::Add<Output = K> + Copy> ops::Add for Matrix<K> {
+impl<K: std::fmt::Display + ops::Add<Output = K> + Clone> ops::Add for Matrix<K> {
     type Output = Self;
 
     // Time: O(n) − Space: O(n)
@@ -74,7 +75,7 @@ impl<K: std::fmt::Display + ops::Add<Output = K> + Copy> ops::Add for Matrix<K>
 
         let mut new_data = Vec::new();
         for i in 0..self.data.len() {
-            new_data.push(self.data[i] + v.data[i]);
+            new_data.push(self.data[i].clone() + v.data[i].clone());
         }
 
         return Matrix {
@@ -85,7 +86,7 @@ impl<K: std::fmt::Display + ops::Add<Output = K> + Copy> ops::Add for Matrix<K>
 }
 
 // Subtraction overload
-impl<K: std::fmt::Display + ops::Sub<Output = K> + Copy> ops::Sub for Matrix<K> {
+impl<K: std::fmt::Display + ops::Sub<Output = K> + Clone> ops::Sub for Matrix<K> {
     type Output = Self;
 
     // Time: O(n) − Space: O(n)
@@ -97,7 +98,7 @@ impl<K: std::fmt::Display + ops::Sub<Output = K> + Copy> ops::Sub for Matrix<K>
 
         let mut new_data = Vec::new();
         for i in 0..self.data.len() {
-            new_data.push(self.data[i] - v.data[i]);
+            new_data.push(self.data[i].clone() - v.data[i].clone());
         }
 
         return Matrix {
@@ -108,7 +109,7 @@ impl<K: std::fmt::Display + ops::Sub<Output = K> + Copy> ops::Sub for Matrix<K>
 }
 
 // Scalar multiplication overload
-impl<K: std::fmt::Display + ops::Mul<Output = K> + Copy> ops::Mul<K> for Matrix<K> {
+impl<K: std::fmt::Display + ops::Mul<Output = K> + Clone> ops::Mul<K> for Matrix<K> {
     type Output = Self;
 
     // Time: O(n) − Space: O(n)
@@ -116,7 +117,7 @@ impl<K: std::fmt::Display + ops::Mul<Output = K> + Copy> ops::Mul<K> for Matrix<
     fn mul(self, a: K) -> Self {
         let mut new_data = Vec::new();
         for i in 0..self.data.len() {
-            new_data.push(self.data[i] * a);
+            new_data.push(self.data[i].clone() * a.clone());
         }
 
         return Matrix {
@@ -137,7 +138,7 @@ impl<
             + std::cmp::PartialOrd
             + std::ops::Neg<Output = K>
             + Default
-            + Copy
+            + Clone
             + Operations
     > Matrix<K>
 {
@@ -165,7 +166,7 @@ impl<
     // }
 
     pub fn get(&self, r: usize, c: usize) -> K {
-        return self.data[r * self.shape.1 + c];
+        return self.data[r * self.shape.1 + c].clone();
     }
 
     pub fn set(&mut self, r: usize, c: usize, value: K) {
@@ -184,7 +185,7 @@ impl<
         }
 
         for i in 0..self.data.len() {
-            self.data[i] = self.data[i] + v.data[i];
+            self.data[i] = self.data[i].clone() + v.data[i].clone();
         }
     }
 
@@ -200,7 +201,7 @@ impl<
         }
 
         for i in 0..self.data.len() {
-            self.data[i] = self.data[i] - v.data[i];
+            self.data[i] = self.data[i].clone() - v.data[i].clone();
         }
     }
 
@@ -208,7 +209,7 @@ impl<
     // where n is the number of elements in the matrix
     pub fn scl(&mut self, a: K) {
         for i in 0..self.data.len() {
-            self.data[i] = self.data[i] * a;
+            self.data[i] = self.data[i].clone() * a.clone();
         }
     }
 
@@ -241,7 +242,7 @@ impl<
             );
         }
 
-        let mut result: Vector<K> = Vector::from(vec![self.data[0]; self.shape.0]);
+        let mut result: Vector<K> = Vector::from(vec![self.data[0].clone(); self.shape.0]);
 
         for c in 0..self.shape.1 {
             let mut column: Vec<K> = Vec::new();
@@ -249,7 +250,7 @@ impl<
                 column.push(self.get(r, c));
             }
             let mut vector: Vector<K> = Vector::from(column);
-            vector.scl(vec.flat()[c]);
+            vector.scl(vec.flat()[c].clone());
 
             if c == 0 {
                 result = vector;
@@ -258,7 +259,7 @@ impl<
             }
         }
 
-        return Vector::from(result.clone());
+        return result;
     }
 
     // Time: O(nmp) − Space: O(np)
@@ -282,7 +283,7 @@ impl<
         for c in 0..mat_shape.1 {
             let mut column: Vec<K> = Vec::new();
             for r in 0..mat_shape.0 {
-                column.push(mat_flat[r * mat_shape.1 + c]);
+                column.push(mat_flat[r * mat_shape.1 + c].clone());
             }
             // p mul_vecs:
             // Each one has time complexity nm → mul_mat: O(nmp)
@@ -312,7 +313,29 @@ impl<
 
     // Time: O(m^2 * n) − Space: O(1)
     // where self is a matrix of shape (m, n)
-    pub fn row_echelon(&self) -> Matrix<K> {
+    pub fn row_echelon(&self) -> Matrix<K>
+    where
+        K: Field,
+    {
+        return self.reduce_rows(&mut None);
+    }
+
+    // Same as row_echelon, but records every elementary row operation
+    // Time: O(m^2 * n + s * m^2) − Space: O(s * (m^2 + mn))
+    // where s is the number of recorded steps
+    pub fn row_echelon_trace(&self) -> RowTrace<K>
+    where
+        K: Field,
+    {
+        let mut steps: Vec<RowStep<K>> = Vec::new();
+        self.reduce_rows(&mut Some(&mut steps));
+        return RowTrace {
+            initial: self.clone(),
+            steps,
+        };
+    }
+
+    fn reduce_rows(&self, trace: &mut Option<&mut Vec<RowStep<K>>>) -> Matrix<K> {
         let mut matrix: Matrix<K> = self.clone();
         let (rows, columns) = matrix.shape();
         let mut pvt_column: usize = 0; // Pivot column
@@ -351,11 +374,18 @@ impl<
                 matrix.set(curr, c, matrix.get(nz, c));
                 matrix.set(nz, c, temp);
             }
+            if nz != curr {
+                record(trace, RowOperation::Swap { a: curr, b: nz }, &matrix);
+            }
 
             let divisor: K = matrix.get(curr, pvt_column);
             if divisor != K::default() {
                 for c in 0..columns {
-                    matrix.set(curr, c, matrix.get(curr, c) / divisor);
+                    matrix.set(curr, c, matrix.get(curr, c) / divisor.clone());
+                }
+                if divisor != K::one() {
+                    let factor = K::one() / divisor.clone();
+                    record(trace, RowOperation::Scale { row: curr, factor }, &matrix);
                 }
             }
 
@@ -369,9 +399,17 @@ impl<
                     for c in 0..columns {
                         matrix.set(
                             r, c,
-                            matrix.get(r, c) - (first * matrix.get(curr, c))
+                            matrix.get(r, c) - (first.clone() * matrix.get(curr, c))
                         );
                     }
+                    if first != K::default() {
+                        let operation = RowOperation::Eliminate {
+                            target: r,
+                            source: curr,
+                            factor: first,
+                        };
+                        record(trace, operation, &matrix);
+                    }
                 }
             }
 
@@ -381,9 +419,17 @@ impl<
                 for c in 0..columns {
                     matrix.set(
                         r, c,
-                        matrix.get(r, c) - (first * matrix.get(curr, c))
+                        matrix.get(r, c) - (first.clone() * matrix.get(curr, c))
                     );
                 }
+                if first != K::default() {
+                    let operation = RowOperation::Eliminate {
+                        target: r,
+                        source: curr,
+                        factor: first,
+                    };
+                    record(trace, operation, &matrix);
+                }
             }
 
             // Move to the next column
@@ -403,40 +449,20 @@ impl<
         }
 
         if self.shape.0 == 2 {
-            return self.data[0] * self.data[3] - self.data[1] * self.data[2];
-        }
-
-        let mut negative: bool = false;
-        let mut sum: K = K::default();
-
-        for col in 0..self.shape.0 {
-            let mut sub_matrix: Vec<Vec<K>> = Vec::new();
-            for r in 1..self.shape.0 {
-                let mut row: Vec<K> = Vec::new();
-                for c in 0..self.shape.1 {
-                    if c != col {
-                        row.push(self.get(r, c));
-                    }
-                }
-                sub_matrix.push(row);
-            }
-
-            let sub_matrix: Matrix<K> = Matrix::from(sub_matrix);
-            let sub_determinant: K = sub_matrix.determinant();
-            if negative {
-                sum = sum - self.get(0, col) * sub_determinant;
-            } else {
-                sum = sum + self.get(0, col) * sub_determinant;
-            }
-            negative = !negative;
+            return K::cross_quotient(
+                self.get(0, 0), self.get(1, 1), self.get(0, 1), self.get(1, 0), K::one()
+            );
         }
 
-        return sum;
+        // Fraction-free, so exact for integers too
+        return self.bareiss().determinant().unwrap();
     }
 
     // Time: O(n^3) − Space: O(n^2)
-    // (would be space O(1) but we need to check if the matrix is singular)
-    pub fn inverse(&self) -> Result<Matrix<K>, &'static str> {
+    pub fn inverse(&self) -> Result<Matrix<K>, &'static str>
+    where
+        K: Field,
+    {
         if !self.is_square() {
             panic!(
                 "Shape {:?} is not a square",
@@ -444,12 +470,6 @@ impl<
             );
         }
 
-        let determinant = self.determinant();
-
-        if determinant == K::default() {
-            return Err("The matrix is singular");
-        }
-
         let mut augmented: Vec<Vec<K>> = Vec::new();
         for _ in 0..self.shape.0 {
             let mut row: Vec<K> = Vec::new();
@@ -463,11 +483,16 @@ impl<
             for c in 0..self.shape.1 {
                 augmented[r][c] = self.get(r, c);
             }
-            augmented[r][self.shape.1 + r] = determinant / determinant;
+            augmented[r][self.shape.1 + r] = K::one();
         }
 
-        let mut augmented: Matrix<K> = Matrix::from(augmented);
-        augmented = augmented.row_echelon();
+        // The left half reduces to the identity unless the matrix is singular,
+        // decided by the same pivot test as rank and nullity
+        let rref = Matrix::from(augmented).reduced_row_echelon_form();
+        if rref.pivots.iter().filter(|&&(_, c)| c < self.shape.1).count() < self.shape.0 {
+            return Err("The matrix is singular");
+        }
+        let augmented: Matrix<K> = rref.matrix;
 
         let mut unaugmented: Vec<Vec<K>> = Vec::new();
         for _ in 0..self.shape.0 {
@@ -488,21 +513,27 @@ impl<
 
         return Ok(unaugmented);
     }
+}
 
-    pub fn rank(&self) -> usize {
-        let rref = self.row_echelon();
-
-        let mut rank: usize = 0;
-        for r in 0..rref.shape.0 {
-            for c in 0..rref.shape.1 {
-                if rref.get(r, c) != K::default() {
-                    rank += 1;
-                    break;
-                }
-            }
-        }
-
-        return rank;
+fn record<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Clone
+        + Operations
+>(
+    trace: &mut Option<&mut Vec<RowStep<K>>>,
+    operation: RowOperation<K>,
+    matrix: &Matrix<K>,
+) {
+    if let Some(steps) = trace {
+        steps.push(RowStep::new(operation, matrix));
     }
 }
 
@@ -1,7 +1,45 @@
 pub mod vector;
 pub mod matrix;
 pub mod complex;
+pub mod rational;
+pub mod bigint;
+pub mod gf;
+pub mod gf2matrix;
+pub mod normal;
+pub mod polynomial;
+pub mod functions;
+pub mod kronecker;
+pub mod schur;
+pub mod sylvester;
+pub mod cofactor;
+pub mod cramer;
+pub mod triangular;
+pub mod banded;
+pub mod symmetric;
+pub mod quaternion;
+pub mod trace;
+pub mod echelon;
+pub mod subspace;
+pub mod span;
+pub mod smatrix;
+pub mod sparse;
 
 pub use vector::*;
 pub use matrix::*;
 pub use complex::*;
+pub use rational::*;
+pub use bigint::*;
+pub use gf::*;
+pub use gf2matrix::*;
+pub use normal::*;
+pub use polynomial::*;
+pub use schur::*;
+pub use quaternion::*;
+pub use trace::*;
+pub use echelon::*;
+pub use span::*;
+pub use smatrix::*;
+pub use sparse::*;
+pub use triangular::*;
+pub use banded::*;
+pub use symmetric::*;
@@ -0,0 +1,284 @@
+use crate::core::matrix::Matrix;
+use crate::core::vector::Vector;
+use crate::operations::Integer;
+
+// u * original == h, with u unimodular (integer, determinant ±1).
+// h is in row echelon form, its pivots are positive and
+// the cells above each pivot lie in [0, pivot).
+pub struct HermiteForm<K: std::fmt::Display> {
+    pub h: Matrix<K>,
+    pub u: Matrix<K>,
+    // (row, column) of each pivot, from top to bottom
+    pub pivots: Vec<(usize, usize)>,
+}
+
+// u * original * v == d, with u and v unimodular.
+// d is diagonal and its non-zero cells, the invariant factors,
+// are positive and each divide the next.
+pub struct SmithForm<K: std::fmt::Display> {
+    pub d: Matrix<K>,
+    pub u: Matrix<K>,
+    pub v: Matrix<K>,
+    pub invariants: Vec<K>,
+}
+
+fn identity<K: Integer>(n: usize) -> Matrix<K> {
+    let mut identity: Matrix<K> = Matrix::from(vec![vec![K::default(); n]; n]);
+    for i in 0..n {
+        identity.set(i, i, K::one());
+    }
+    return identity;
+}
+
+// (g, x, y) with x * a + y * b == g == gcd(a, b) >= 0
+fn extended_gcd<K: Integer>(a: K, b: K) -> (K, K, K) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (K::one(), K::default());
+    let (mut old_y, mut y) = (K::default(), K::one());
+    while r != K::default() {
+        let q = old_r.clone() / r.clone();
+        (old_r, r) = (r.clone(), old_r - q.clone() * r);
+        (old_x, x) = (x.clone(), old_x - q.clone() * x);
+        (old_y, y) = (y.clone(), old_y - q * y);
+    }
+    if old_r < K::default() {
+        return (-old_r, -old_x, -old_y);
+    }
+    return (old_r, old_x, old_y);
+}
+
+// Largest q with q * b <= a, for b > 0
+fn floor_div<K: Integer>(a: K, b: K) -> K {
+    let q = a.clone() / b.clone();
+    if a.clone() % b != K::default() && a < K::default() {
+        return q - K::one();
+    }
+    return q;
+}
+
+// Transform of combine_rows and combine_columns exchanging i and j
+fn exchange<K: Integer>() -> (K, K, K, K) {
+    return (K::default(), K::one(), K::one(), K::default());
+}
+
+// Transform of combine_rows and combine_columns adding q times j to i
+fn add_multiple<K: Integer>(q: K) -> (K, K, K, K) {
+    return (K::one(), q, K::default(), K::one());
+}
+
+// (row i, row j) <- (a * row i + b * row j, c * row i + d * row j)
+fn combine_rows<K: Integer>(m: &mut Matrix<K>, i: usize, j: usize, (a, b, c, d): &(K, K, K, K)) {
+    for col in 0..m.shape().1 {
+        let (x, y) = (m.get(i, col), m.get(j, col));
+        m.set(i, col, a.clone() * x.clone() + b.clone() * y.clone());
+        m.set(j, col, c.clone() * x + d.clone() * y);
+    }
+}
+
+fn negate_row<K: Integer>(m: &mut Matrix<K>, i: usize) {
+    for col in 0..m.shape().1 {
+        m.set(i, col, -m.get(i, col));
+    }
+}
+
+// (column i, column j) <- (a * column i + b * column j, c * column i + d * column j)
+fn combine_columns<K: Integer>(m: &mut Matrix<K>, i: usize, j: usize, (a, b, c, d): &(K, K, K, K)) {
+    for row in 0..m.shape().0 {
+        let (x, y) = (m.get(row, i), m.get(row, j));
+        m.set(row, i, a.clone() * x.clone() + b.clone() * y.clone());
+        m.set(row, j, c.clone() * x + d.clone() * y);
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Clone
+            + Integer
+    > Matrix<K>
+{
+    // Row-style Hermite normal form, by extended gcd row combinations
+    // Time: O(m^2 * n) arithmetic operations − Space: O(m^2 + mn)
+    // where self is a matrix of shape (m, n)
+    pub fn hermite_normal_form(&self) -> HermiteForm<K> {
+        let (rows, columns) = self.shape();
+        let zero = K::default();
+        let mut h = self.clone();
+        let mut u: Matrix<K> = identity(rows);
+        let mut pivots: Vec<(usize, usize)> = Vec::new();
+
+        let mut curr = 0;
+        for col in 0..columns {
+            if curr == rows {
+                break;
+            }
+
+            // Gather the gcd of the column in the current row
+            for r in curr + 1..rows {
+                let b = h.get(r, col);
+                if b == zero {
+                    continue;
+                }
+                let a = h.get(curr, col);
+                let (g, x, y) = extended_gcd(a.clone(), b.clone());
+                // Determinant x * a / g + y * b / g == 1
+                let transform = (x, y, -(b / g.clone()), a / g);
+                combine_rows(&mut h, curr, r, &transform);
+                combine_rows(&mut u, curr, r, &transform);
+            }
+
+            let pivot = h.get(curr, col);
+            if pivot == zero {
+                continue;
+            }
+            if pivot < zero {
+                negate_row(&mut h, curr);
+                negate_row(&mut u, curr);
+            }
+
+            // Reduce the cells above the pivot into [0, pivot)
+            let pivot = h.get(curr, col);
+            for r in 0..curr {
+                let q = floor_div(h.get(r, col), pivot.clone());
+                if q != zero {
+                    let transform = add_multiple(-q);
+                    combine_rows(&mut h, r, curr, &transform);
+                    combine_rows(&mut u, r, curr, &transform);
+                }
+            }
+
+            pivots.push((curr, col));
+            curr += 1;
+        }
+
+        return HermiteForm { h, u, pivots };
+    }
+
+    // Smith normal form, by alternating row and column reductions
+    // around the smallest non-zero cell
+    // Time: O(min(m, n) * mn * log(max |cell|)) arithmetic operations − Space: O(m^2 + n^2)
+    pub fn smith_normal_form(&self) -> SmithForm<K> {
+        let (rows, columns) = self.shape();
+        let zero = K::default();
+        let mut d = self.clone();
+        let mut u: Matrix<K> = identity(rows);
+        let mut v: Matrix<K> = identity(columns);
+        let mut invariants: Vec<K> = Vec::new();
+
+        for t in 0..rows.min(columns) {
+            loop {
+                // Smallest non-zero cell of the remaining block, moved to (t, t)
+                let mut smallest: Option<(usize, usize)> = None;
+                for r in t..rows {
+                    for c in t..columns {
+                        let cell = d.get(r, c);
+                        if cell == zero {
+                            continue;
+                        }
+                        match smallest {
+                            Some((sr, sc)) if cell.abs() >= d.get(sr, sc).abs() => {}
+                            _ => smallest = Some((r, c)),
+                        }
+                    }
+                }
+                let (r, c) = match smallest {
+                    Some(position) => position,
+                    None => break,
+                };
+                if r != t {
+                    combine_rows(&mut d, t, r, &exchange());
+                    combine_rows(&mut u, t, r, &exchange());
+                }
+                if c != t {
+                    combine_columns(&mut d, t, c, &exchange());
+                    combine_columns(&mut v, t, c, &exchange());
+                }
+
+                // Remainders of the pivot row and column, smaller than the pivot
+                let pivot = d.get(t, t);
+                let mut clean = true;
+                for r in t + 1..rows {
+                    let transform = add_multiple(-(d.get(r, t) / pivot.clone()));
+                    combine_rows(&mut d, r, t, &transform);
+                    combine_rows(&mut u, r, t, &transform);
+                    clean = clean && d.get(r, t) == zero;
+                }
+                for c in t + 1..columns {
+                    let transform = add_multiple(-(d.get(t, c) / pivot.clone()));
+                    combine_columns(&mut d, c, t, &transform);
+                    combine_columns(&mut v, c, t, &transform);
+                    clean = clean && d.get(t, c) == zero;
+                }
+                if !clean {
+                    continue;
+                }
+
+                // The pivot must divide the rest of the block,
+                // otherwise bring a remainder into its row and start over
+                let mut divisible = true;
+                'search: for r in t + 1..rows {
+                    for c in t + 1..columns {
+                        if d.get(r, c) % pivot.clone() != zero {
+                            combine_rows(&mut d, t, r, &add_multiple(K::one()));
+                            combine_rows(&mut u, t, r, &add_multiple(K::one()));
+                            divisible = false;
+                            break 'search;
+                        }
+                    }
+                }
+                if divisible {
+                    break;
+                }
+            }
+
+            let pivot = d.get(t, t);
+            if pivot == zero {
+                break;
+            }
+            if pivot < zero {
+                negate_row(&mut d, t);
+                negate_row(&mut u, t);
+            }
+            invariants.push(d.get(t, t));
+        }
+
+        return SmithForm { d, u, v, invariants };
+    }
+
+    // One integer solution of self * x = b, from the Smith normal form:
+    // with u * self * v = d, solve d * y = u * b then x = v * y.
+    // Every other solution adds an integer combination of the
+    // last n - rank columns of the Smith form's v.
+    // Time: as smith_normal_form − Space: O(m^2 + n^2)
+    pub fn solve_diophantine(&self, b: &Vector<K>) -> Result<Vector<K>, &'static str> {
+        let (rows, columns) = self.shape();
+        if b.shape().0 != rows {
+            return Err("The right-hand side does not match the matrix");
+        }
+        let smith = self.smith_normal_form();
+        let c = smith.u.mul_vec(b.clone()).flat();
+
+        let mut y: Vec<K> = vec![K::default(); columns];
+        for i in 0..rows {
+            let divisor = if i < smith.invariants.len() { smith.invariants[i].clone() } else { K::default() };
+            if divisor == K::default() {
+                if c[i] != K::default() {
+                    return Err("The system has no solution");
+                }
+            } else {
+                if c[i].clone() % divisor.clone() != K::default() {
+                    return Err("The system has no integer solution");
+                }
+                y[i] = c[i].clone() / divisor;
+            }
+        }
+        return Ok(smith.v.mul_vec(Vector::from(y)));
+    }
+}
@@ -0,0 +1,518 @@
+use std::ops::{Add, Sub, Mul, Neg};
+use std::fmt::Display;
+
+use crate::core::complex::Complex;
+use crate::core::matrix::Matrix;
+use crate::core::vector::Vector;
+use crate::operations::{Field, Operations};
+
+// c0 + c1 x + c2 x^2 + ..., stored lowest degree first
+// without trailing zero coefficients, so the zero polynomial is empty
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial<K: Display> {
+    coefficients: Vec<K>,
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > Polynomial<K>
+{
+    pub fn new(coefficients: Vec<K>) -> Polynomial<K> {
+        let mut coefficients = coefficients;
+        while coefficients.last() == Some(&K::default()) {
+            coefficients.pop();
+        }
+        return Polynomial { coefficients };
+    }
+
+    pub fn zero() -> Polynomial<K> {
+        return Polynomial { coefficients: Vec::new() };
+    }
+
+    // coefficient * x^degree
+    pub fn monomial(coefficient: K, degree: usize) -> Polynomial<K> {
+        let mut coefficients = vec![K::default(); degree + 1];
+        coefficients[degree] = coefficient;
+        return Polynomial::new(coefficients);
+    }
+
+    pub fn coefficients(&self) -> &[K] {
+        return &self.coefficients;
+    }
+
+    // None for the zero polynomial
+    pub fn degree(&self) -> Option<usize> {
+        return self.coefficients.len().checked_sub(1);
+    }
+
+    pub fn leading(&self) -> K {
+        return self.coefficients.last().copied().unwrap_or_default();
+    }
+
+    pub fn is_zero(&self) -> bool {
+        return self.coefficients.is_empty();
+    }
+
+    // Horner's scheme
+    // Time: O(d) − Space: O(1)
+    pub fn eval(&self, x: K) -> K {
+        let mut value = K::default();
+        for &c in self.coefficients.iter().rev() {
+            value = value * x + c;
+        }
+        return value;
+    }
+
+    // Horner's scheme with matrix products, c0 standing for c0 * I
+    // Time: O(d * n^3) − Space: O(n^2)
+    pub fn eval_matrix(&self, m: &Matrix<K>) -> Matrix<K> {
+        if !m.is_square() {
+            panic!("Shape {:?} is not a square", m.shape());
+        }
+        let n = m.shape().0;
+        let mut value: Matrix<K> = Matrix::from(vec![vec![K::default(); n]; n]);
+        for &c in self.coefficients.iter().rev() {
+            value = value.mul_mat(m.clone());
+            for i in 0..n {
+                value.set(i, i, value.get(i, i) + c);
+            }
+        }
+        return value;
+    }
+
+    // Time: O(d) − Space: O(d)
+    pub fn derivative(&self) -> Polynomial<K> {
+        let mut coefficients: Vec<K> = Vec::new();
+        let mut power = K::default();
+        for &c in self.coefficients.iter().skip(1) {
+            power = power + K::one();
+            coefficients.push(c * power);
+        }
+        return Polynomial::new(coefficients);
+    }
+
+    pub fn scl(&mut self, a: K) {
+        let scaled: Vec<K> = self.coefficients.iter().map(|&c| c * a).collect();
+        *self = Polynomial::new(scaled);
+    }
+
+    // (quotient, remainder) with self = quotient * divisor + remainder
+    // and deg remainder < deg divisor
+    // Time: O(d^2) − Space: O(d)
+    pub fn div_rem(&self, divisor: &Polynomial<K>) -> Result<(Polynomial<K>, Polynomial<K>), &'static str>
+    where
+        K: Field,
+    {
+        let d = match divisor.degree() {
+            Some(d) => d,
+            None => return Err("Division by the zero polynomial"),
+        };
+        let mut remainder = self.coefficients.clone();
+        if remainder.len() <= d {
+            return Ok((Polynomial::zero(), self.clone()));
+        }
+
+        let mut quotient = vec![K::default(); remainder.len() - d];
+        for k in (0..quotient.len()).rev() {
+            let q = remainder[k + d] / divisor.leading();
+            quotient[k] = q;
+            for (i, &c) in divisor.coefficients.iter().enumerate() {
+                remainder[k + i] = remainder[k + i] - q * c;
+            }
+            // Exactly cancelled, even when rounding says otherwise
+            remainder[k + d] = K::default();
+        }
+        remainder.truncate(d);
+        return Ok((Polynomial::new(quotient), Polynomial::new(remainder)));
+    }
+
+    // Same roots, leading coefficient one
+    pub fn monic(&self) -> Result<Polynomial<K>, &'static str>
+    where
+        K: Field,
+    {
+        if self.is_zero() {
+            return Err("The zero polynomial has no leading coefficient");
+        }
+        let leading = self.leading();
+        return Ok(Polynomial::new(self.coefficients.iter().map(|&c| c / leading).collect()));
+    }
+}
+
+// Lowest degree first
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > From<Vec<K>> for Polynomial<K>
+{
+    fn from(coefficients: Vec<K>) -> Polynomial<K> {
+        return Polynomial::new(coefficients);
+    }
+}
+
+// Complex arithmetic on (real, imaginary) pairs, in double precision
+fn c_mul(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    return (a.0 * b.0 - a.1 * b.1, a.0 * b.1 + a.1 * b.0);
+}
+
+fn c_div(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    let norm = b.0 * b.0 + b.1 * b.1;
+    return ((a.0 * b.0 + a.1 * b.1) / norm, (a.1 * b.0 - a.0 * b.1) / norm);
+}
+
+fn c_abs(a: (f64, f64)) -> f64 {
+    return a.0.hypot(a.1);
+}
+
+// Durand-Kerner: every root estimate moves by p(z) / prod (z - other estimates)
+// simultaneously, starting from a circle holding all the roots
+// Time: O(d^2) per iteration − Space: O(d)
+fn durand_kerner(coefficients: &[(f64, f64)]) -> Result<Vec<(f64, f64)>, &'static str> {
+    let Some(degree) = coefficients.len().checked_sub(1) else {
+        return Err("The zero polynomial vanishes everywhere");
+    };
+
+    // Zero roots are exact, the others come from the deflated polynomial
+    let zeros = coefficients.iter().take_while(|&&c| c == (0., 0.)).count();
+    let mut roots: Vec<(f64, f64)> = vec![(0., 0.); zeros];
+    let leading = coefficients[degree];
+    let monic: Vec<(f64, f64)> = coefficients[zeros..].iter().map(|&c| c_div(c, leading)).collect();
+    let n = degree - zeros;
+    if n == 0 {
+        return Ok(roots);
+    }
+
+    // Cauchy's bound
+    let radius = 1. + monic[..n].iter().map(|&c| c_abs(c)).fold(0., f64::max);
+    let mut z: Vec<(f64, f64)> = (0..n)
+        .map(|k| {
+            let angle = 2. * std::f64::consts::PI * k as f64 / n as f64 + 0.4;
+            (radius * angle.cos(), radius * angle.sin())
+        })
+        .collect();
+
+    let mut largest_step: f64 = f64::INFINITY;
+    for _ in 0..2000 {
+        largest_step = 0.;
+        for k in 0..n {
+            let mut value = (0., 0.);
+            for &c in monic.iter().rev() {
+                value = c_mul(value, z[k]);
+                value = (value.0 + c.0, value.1 + c.1);
+            }
+            let mut denominator = (1., 0.);
+            for j in (0..n).filter(|&j| j != k) {
+                denominator = c_mul(denominator, (z[k].0 - z[j].0, z[k].1 - z[j].1));
+            }
+            let step = c_div(value, denominator);
+            z[k] = (z[k].0 - step.0, z[k].1 - step.1);
+            largest_step = largest_step.max(c_abs(step) / (1. + c_abs(z[k])));
+        }
+        if largest_step <= 1e-14 {
+            break;
+        }
+    }
+    // Repeated roots only converge linearly and then jitter with rounding,
+    // so a few stalled digits are still accepted
+    if largest_step.is_nan() || largest_step > 1e-6 {
+        return Err("The root iteration did not converge");
+    }
+
+    roots.extend(z);
+    roots.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+    return Ok(roots);
+}
+
+impl Polynomial<f64> {
+    // All the complex roots as (real, imaginary) pairs, with multiplicity,
+    // sorted by real part. Imaginary parts within rounding of zero are dropped.
+    pub fn roots(&self) -> Result<Vec<(f64, f64)>, &'static str> {
+        let coefficients: Vec<(f64, f64)> = self.coefficients.iter().map(|&c| (c, 0.)).collect();
+        let mut roots = durand_kerner(&coefficients)?;
+        for root in roots.iter_mut() {
+            if root.1.abs() <= 1e-9 * (1. + root.0.abs()) {
+                root.1 = 0.;
+            }
+        }
+        roots.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+        return Ok(roots);
+    }
+}
+
+impl Polynomial<Complex> {
+    // All the roots with multiplicity, sorted by real part
+    pub fn roots(&self) -> Result<Vec<Complex>, &'static str> {
+        let coefficients: Vec<(f64, f64)> = self
+            .coefficients
+            .iter()
+            .map(|c| (c.real() as f64, c.imaginary() as f64))
+            .collect();
+        let roots = durand_kerner(&coefficients)?;
+        return Ok(roots.iter().map(|&(r, i)| Complex::new(r as f32, i as f32)).collect());
+    }
+}
+
+// x^3 - 6x^2 + 11x - 6
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > Display for Polynomial<K>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.coefficients.is_empty() {
+            return write!(f, "0");
+        }
+        let zero = K::default();
+        let mut first = true;
+        for (degree, &c) in self.coefficients.iter().enumerate().rev() {
+            if c == zero {
+                continue;
+            }
+            // Both checks so that partially ordered scalars only lose
+            // their sign when it is unambiguous
+            let negative = c < zero && -c > zero;
+            let magnitude = if negative { -c } else { c };
+            match (first, negative) {
+                (true, true) => write!(f, "-")?,
+                (true, false) => {}
+                (false, true) => write!(f, " - ")?,
+                (false, false) => write!(f, " + ")?,
+            }
+            first = false;
+            if magnitude != K::one() || degree == 0 {
+                write!(f, "{}", magnitude)?;
+            }
+            match degree {
+                0 => {}
+                1 => write!(f, "x")?,
+                _ => write!(f, "x^{}", degree)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > Add for Polynomial<K>
+{
+    type Output = Polynomial<K>;
+
+    // Time: O(d) − Space: O(d)
+    fn add(self, p: Polynomial<K>) -> Polynomial<K> {
+        let length = self.coefficients.len().max(p.coefficients.len());
+        let sum: Vec<K> = (0..length)
+            .map(|i| {
+                let a = self.coefficients.get(i).copied().unwrap_or_default();
+                let b = p.coefficients.get(i).copied().unwrap_or_default();
+                a + b
+            })
+            .collect();
+        return Polynomial::new(sum);
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > Sub for Polynomial<K>
+{
+    type Output = Polynomial<K>;
+
+    fn sub(self, p: Polynomial<K>) -> Polynomial<K> {
+        return self + -p;
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > Mul for Polynomial<K>
+{
+    type Output = Polynomial<K>;
+
+    // Time: O(d^2) − Space: O(d)
+    fn mul(self, p: Polynomial<K>) -> Polynomial<K> {
+        if self.coefficients.is_empty() || p.coefficients.is_empty() {
+            return Polynomial::zero();
+        }
+        let mut product = vec![K::default(); self.coefficients.len() + p.coefficients.len() - 1];
+        for (i, &a) in self.coefficients.iter().enumerate() {
+            for (j, &b) in p.coefficients.iter().enumerate() {
+                product[i + j] = product[i + j] + a * b;
+            }
+        }
+        return Polynomial::new(product);
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > Neg for Polynomial<K>
+{
+    type Output = Polynomial<K>;
+
+    fn neg(self) -> Polynomial<K> {
+        return Polynomial::new(self.coefficients.iter().map(|&c| -c).collect());
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > Matrix<K>
+{
+    // det(xI - self), by the Berkowitz algorithm: the coefficients of the leading
+    // r x r block follow from those of the (r - 1) x (r - 1) one, times the
+    // Toeplitz matrix of 1, -a_rr, -R C, -R M C, ..., -R M^(r - 2) C,
+    // where M is the smaller block, C the column above a_rr and R the row to its left.
+    // Division free, so exact over any ring, finite fields of small characteristic included.
+    // Time: O(n^4) − Space: O(n)
+    pub fn characteristic_polynomial(&self) -> Polynomial<K> {
+        if !self.is_square() {
+            panic!("Shape {:?} is not a square", self.shape());
+        }
+        let n = self.shape().0;
+        // Highest degree first
+        let mut coefficients = vec![K::one()];
+
+        for r in 0..n {
+            let mut toeplitz = vec![K::one(), -self.get(r, r)];
+            let mut column: Vec<K> = (0..r).map(|i| self.get(i, r)).collect();
+            for _ in 0..r {
+                let mut product = K::default();
+                for j in 0..r {
+                    product = product + self.get(r, j) * column[j];
+                }
+                toeplitz.push(-product);
+                column = (0..r)
+                    .map(|i| {
+                        let mut sum = K::default();
+                        for j in 0..r {
+                            sum = sum + self.get(i, j) * column[j];
+                        }
+                        sum
+                    })
+                    .collect();
+            }
+
+            let mut next = vec![K::default(); r + 2];
+            for i in 0..r + 2 {
+                for j in 0..=i.min(r) {
+                    next[i] = next[i] + toeplitz[i - j] * coefficients[j];
+                }
+            }
+            coefficients = next;
+        }
+        coefficients.reverse();
+        return Polynomial::new(coefficients);
+    }
+
+    // Monic polynomial of least degree with p(self) = 0: the first power
+    // of self that is a combination of the lower ones gives its coefficients
+    // Time: O(n^6) − Space: O(n^3)
+    pub fn minimal_polynomial(&self) -> Polynomial<K>
+    where
+        K: Field,
+    {
+        if !self.is_square() {
+            panic!("Shape {:?} is not a square", self.shape());
+        }
+        let n = self.shape().0;
+        let mut identity: Matrix<K> = Matrix::from(vec![vec![K::default(); n]; n]);
+        for i in 0..n {
+            identity.set(i, i, K::one());
+        }
+
+        // Column k holds the flattened self^k
+        let mut powers: Vec<Vec<K>> = vec![identity.flat()];
+        let mut power = identity;
+        for k in 1..=n {
+            power = power.mul_mat(self.clone());
+            let system: Matrix<K> = Matrix::from((powers.concat(), (n * n, k)));
+            if let Ok(c) = system.solve(&Vector::from(power.flat())) {
+                let mut coefficients: Vec<K> = c.flat().iter().map(|&c| -c).collect();
+                coefficients.push(K::one());
+                return Polynomial::new(coefficients);
+            }
+            powers.push(power.flat());
+        }
+        // Unreachable by Cayley-Hamilton
+        return self.characteristic_polynomial();
+    }
+}
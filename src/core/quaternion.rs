@@ -0,0 +1,320 @@
+use std::ops::{Add, Sub, Mul, Neg};
+use std::fmt::Display;
+
+use crate::core::matrix::Matrix;
+use crate::core::vector::Vector;
+use crate::operations::{Operations, Trigonometry};
+
+// w + xi + yj + zk
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Quaternion<K> {
+    w: K,
+    x: K,
+    y: K,
+    z: K,
+}
+
+impl<K> Quaternion<K> {
+    pub fn new(w: K, x: K, y: K, z: K) -> Quaternion<K> {
+        return Quaternion { w, x, y, z };
+    }
+}
+
+impl<K> From<(K, K, K, K)> for Quaternion<K> {
+    fn from(data: (K, K, K, K)) -> Quaternion<K> {
+        return Quaternion { w: data.0, x: data.1, y: data.2, z: data.3 };
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+            + Trigonometry
+    > Quaternion<K>
+{
+    pub fn identity() -> Quaternion<K> {
+        return Quaternion::new(K::one(), K::default(), K::default(), K::default());
+    }
+
+    // Real part
+    pub fn scalar(&self) -> K {
+        return self.w;
+    }
+
+    // Imaginary part
+    pub fn vector(&self) -> Vector<K> {
+        return Vector::from(vec![self.x, self.y, self.z]);
+    }
+
+    pub fn conjugate(&self) -> Quaternion<K> {
+        return Quaternion::new(self.w, -self.x, -self.y, -self.z);
+    }
+
+    pub fn dot(&self, q: &Quaternion<K>) -> K {
+        return self.w * q.w + self.x * q.x + self.y * q.y + self.z * q.z;
+    }
+
+    pub fn norm(&self) -> K {
+        return self.dot(self).sqrt();
+    }
+
+    pub fn normalize(&self) -> Result<Quaternion<K>, &'static str> {
+        let norm = self.norm();
+        if norm.is_zero() {
+            return Err("The zero quaternion cannot be normalized");
+        }
+        return Ok(*self * (K::one() / norm));
+    }
+
+    pub fn inverse(&self) -> Result<Quaternion<K>, &'static str> {
+        let squared = self.dot(self);
+        if squared.is_zero() {
+            return Err("The zero quaternion has no inverse");
+        }
+        return Ok(self.conjugate() * (K::one() / squared));
+    }
+
+    // Rotation of `angle` radians around `axis`,
+    // counter-clockwise as transform::rotation
+    pub fn from_axis_angle(axis: &Vector<K>, angle: K) -> Result<Quaternion<K>, &'static str> {
+        if axis.shape().0 != 3 {
+            return Err("The axis must have shape (3, 1)");
+        }
+        let a = axis.normalize()?.flat();
+        let half = angle / (K::one() + K::one());
+        let sin = half.sin();
+        return Ok(Quaternion::new(half.cos(), a[0] * sin, a[1] * sin, a[2] * sin));
+    }
+
+    // (axis, angle) of a unit quaternion,
+    // the axis is arbitrary (x) when the angle is zero
+    pub fn to_axis_angle(self) -> (Vector<K>, K) {
+        let two = K::one() + K::one();
+        let sin = self.vector().norm();
+        let angle = two * sin.atan2(self.w);
+        if sin.is_zero() {
+            return (Vector::from(vec![K::one(), K::default(), K::default()]), angle);
+        }
+        let mut axis = self.vector();
+        axis.scl(K::one() / sin);
+        return (axis, angle);
+    }
+
+    // Rotation by yaw around z, then pitch around y, then roll around x
+    // (intrinsic Z-Y-X, i.e. R = Rz(yaw) * Ry(pitch) * Rx(roll)), in radians
+    pub fn from_euler(roll: K, pitch: K, yaw: K) -> Quaternion<K> {
+        let two = K::one() + K::one();
+        let (sr, cr) = ((roll / two).sin(), (roll / two).cos());
+        let (sp, cp) = ((pitch / two).sin(), (pitch / two).cos());
+        let (sy, cy) = ((yaw / two).sin(), (yaw / two).cos());
+        return Quaternion::new(
+            cr * cp * cy + sr * sp * sy,
+            sr * cp * cy - cr * sp * sy,
+            cr * sp * cy + sr * cp * sy,
+            cr * cp * sy - sr * sp * cy,
+        );
+    }
+
+    // (roll, pitch, yaw) of a unit quaternion, inverse of from_euler
+    pub fn to_euler(self) -> (K, K, K) {
+        let (w, x, y, z) = (self.w, self.x, self.y, self.z);
+        let one = K::one();
+        let two = one + one;
+
+        let roll = (two * (w * x + y * z)).atan2(one - two * (x * x + y * y));
+        let mut sin_pitch = two * (w * y - z * x);
+        if sin_pitch > one {
+            sin_pitch = one;
+        } else if sin_pitch < -one {
+            sin_pitch = -one;
+        }
+        let pitch = sin_pitch.atan2((one - sin_pitch * sin_pitch).sqrt());
+        let yaw = (two * (w * z + x * y)).atan2(one - two * (y * y + z * z));
+
+        return (roll, pitch, yaw);
+    }
+
+    // 3x3 rotation matrix of a unit quaternion
+    pub fn to_rotation_matrix(self) -> Matrix<K> {
+        let (w, x, y, z) = (self.w, self.x, self.y, self.z);
+        let one = K::one();
+        let two = one + one;
+        return Matrix::from(vec![
+            vec![
+                one - two * (y * y + z * z),
+                two * (x * y - w * z),
+                two * (x * z + w * y),
+            ],
+            vec![
+                two * (x * y + w * z),
+                one - two * (x * x + z * z),
+                two * (y * z - w * x),
+            ],
+            vec![
+                two * (x * z - w * y),
+                two * (y * z + w * x),
+                one - two * (x * x + y * y),
+            ],
+        ]);
+    }
+
+    // 4x4 homogeneous rotation, as built by the transform module
+    pub fn to_homogeneous(self) -> Matrix<K> {
+        let rotation = self.to_rotation_matrix();
+        let mut m: Matrix<K> = Matrix::from(vec![vec![K::default(); 4]; 4]);
+        for r in 0..3 {
+            for c in 0..3 {
+                m.set(r, c, rotation.get(r, c));
+            }
+        }
+        m.set(3, 3, K::one());
+        return m;
+    }
+
+    // Shepperd's method, from the top-left 3x3 block of a 3x3 or 4x4 rotation
+    pub fn from_rotation_matrix(m: &Matrix<K>) -> Result<Quaternion<K>, &'static str> {
+        if m.shape() != (3, 3) && m.shape() != (4, 4) {
+            return Err("The rotation matrix must have shape (3, 3) or (4, 4)");
+        }
+        let one = K::one();
+        let four = one + one + one + one;
+        let (m00, m11, m22) = (m.get(0, 0), m.get(1, 1), m.get(2, 2));
+        let trace = m00 + m11 + m22;
+
+        // Divide by the largest of the four candidates to stay accurate
+        let q = if trace > K::default() {
+            let s = (trace + one).sqrt() * (one + one);
+            Quaternion::new(
+                s / four,
+                (m.get(2, 1) - m.get(1, 2)) / s,
+                (m.get(0, 2) - m.get(2, 0)) / s,
+                (m.get(1, 0) - m.get(0, 1)) / s,
+            )
+        } else if m00 > m11 && m00 > m22 {
+            let s = (one + m00 - m11 - m22).sqrt() * (one + one);
+            Quaternion::new(
+                (m.get(2, 1) - m.get(1, 2)) / s,
+                s / four,
+                (m.get(0, 1) + m.get(1, 0)) / s,
+                (m.get(0, 2) + m.get(2, 0)) / s,
+            )
+        } else if m11 > m22 {
+            let s = (one + m11 - m00 - m22).sqrt() * (one + one);
+            Quaternion::new(
+                (m.get(0, 2) - m.get(2, 0)) / s,
+                (m.get(0, 1) + m.get(1, 0)) / s,
+                s / four,
+                (m.get(1, 2) + m.get(2, 1)) / s,
+            )
+        } else {
+            let s = (one + m22 - m00 - m11).sqrt() * (one + one);
+            Quaternion::new(
+                (m.get(1, 0) - m.get(0, 1)) / s,
+                (m.get(0, 2) + m.get(2, 0)) / s,
+                (m.get(1, 2) + m.get(2, 1)) / s,
+                s / four,
+            )
+        };
+
+        return q.normalize();
+    }
+
+    // Spherical interpolation between two unit quaternions,
+    // along the shortest path
+    pub fn slerp(&self, q: &Quaternion<K>, t: K) -> Quaternion<K> {
+        let one = K::one();
+        let mut end = *q;
+        let mut cos = self.dot(q);
+        if cos < K::default() {
+            end = -end;
+            cos = -cos;
+        }
+
+        // Nearly identical: fall back to a normalised lerp
+        if cos > K::from_f64(0.9995) {
+            let lerp = *self * (one - t) + end * t;
+            return lerp.normalize().unwrap_or(lerp);
+        }
+
+        let theta = cos.acos();
+        let sin = theta.sin();
+        return *self * (((one - t) * theta).sin() / sin) + end * ((t * theta).sin() / sin);
+    }
+
+    // q * (0, v) * q^-1
+    pub fn rotate(&self, v: &Vector<K>) -> Result<Vector<K>, &'static str> {
+        if v.shape().0 != 3 {
+            return Err("The vector must have shape (3, 1)");
+        }
+        let p = v.flat();
+        let pure = Quaternion::new(K::default(), p[0], p[1], p[2]);
+        return Ok((*self * pure * self.inverse()?).vector());
+    }
+}
+
+impl<K: Display> Display for Quaternion<K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        return write!(f, "({} + {}i + {}j + {}k)", self.w, self.x, self.y, self.z);
+    }
+}
+
+impl<K: Add<Output = K>> Add for Quaternion<K> {
+    type Output = Quaternion<K>;
+
+    fn add(self, q: Quaternion<K>) -> Quaternion<K> {
+        return Quaternion::new(self.w + q.w, self.x + q.x, self.y + q.y, self.z + q.z);
+    }
+}
+
+impl<K: Sub<Output = K>> Sub for Quaternion<K> {
+    type Output = Quaternion<K>;
+
+    fn sub(self, q: Quaternion<K>) -> Quaternion<K> {
+        return Quaternion::new(self.w - q.w, self.x - q.x, self.y - q.y, self.z - q.z);
+    }
+}
+
+// Hamilton product
+impl<K: Add<Output = K> + Sub<Output = K> + Mul<Output = K> + Copy> Mul for Quaternion<K> {
+    type Output = Quaternion<K>;
+
+    fn mul(self, q: Quaternion<K>) -> Quaternion<K> {
+        return Quaternion::new(
+            self.w * q.w - self.x * q.x - self.y * q.y - self.z * q.z,
+            self.w * q.x + self.x * q.w + self.y * q.z - self.z * q.y,
+            self.w * q.y - self.x * q.z + self.y * q.w + self.z * q.x,
+            self.w * q.z + self.x * q.y - self.y * q.x + self.z * q.w,
+        );
+    }
+}
+
+impl<K: Mul<Output = K> + Copy> Mul<K> for Quaternion<K> {
+    type Output = Quaternion<K>;
+
+    fn mul(self, a: K) -> Quaternion<K> {
+        return Quaternion::new(self.w * a, self.x * a, self.y * a, self.z * a);
+    }
+}
+
+impl<K: Neg<Output = K>> Neg for Quaternion<K> {
+    type Output = Quaternion<K>;
+
+    fn neg(self) -> Quaternion<K> {
+        return Quaternion::new(-self.w, -self.x, -self.y, -self.z);
+    }
+}
+
+impl<K: Default> Default for Quaternion<K> {
+    fn default() -> Quaternion<K> {
+        return Quaternion::new(K::default(), K::default(), K::default(), K::default());
+    }
+}
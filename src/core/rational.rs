@@ -0,0 +1,208 @@
+use std::ops::{Add, Sub, Mul, Div, Neg};
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::operations::{Field, Integer, Operations};
+
+// Exact fraction numerator / denominator, always reduced
+// with a positive denominator so that equal values compare equal
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Rational<T = i64> {
+    numerator: T,
+    denominator: T,
+}
+
+impl<T: Integer> Rational<T> {
+    pub fn new(numerator: T, denominator: T) -> Rational<T> {
+        if denominator == T::default() {
+            panic!("The denominator of a rational cannot be zero");
+        }
+        let gcd = T::gcd(numerator.clone(), denominator.clone());
+        let (mut numerator, mut denominator) = (numerator / gcd.clone(), denominator / gcd);
+        if denominator < T::default() {
+            numerator = -numerator;
+            denominator = -denominator;
+        }
+        return Rational { numerator, denominator };
+    }
+
+    pub fn numerator(&self) -> T {
+        return self.numerator.clone();
+    }
+
+    pub fn denominator(&self) -> T {
+        return self.denominator.clone();
+    }
+
+    pub fn is_integer(&self) -> bool {
+        return self.denominator == T::one();
+    }
+
+    pub fn recip(&self) -> Result<Rational<T>, &'static str> {
+        if self.numerator == T::default() {
+            return Err("Zero has no reciprocal");
+        }
+        return Ok(Rational::new(self.denominator.clone(), self.numerator.clone()));
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        return self.numerator.clone().to_f64() / self.denominator.clone().to_f64();
+    }
+}
+
+impl<T: Integer> From<T> for Rational<T> {
+    fn from(numerator: T) -> Rational<T> {
+        return Rational { numerator, denominator: T::one() };
+    }
+}
+
+impl<T: Integer> From<(T, T)> for Rational<T> {
+    fn from(data: (T, T)) -> Rational<T> {
+        return Rational::new(data.0, data.1);
+    }
+}
+
+// "113/174", "-3" or "3/1"
+impl<T: Integer + FromStr> FromStr for Rational<T> {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Rational<T>, String> {
+        let parse = |s: &str| {
+            s.trim().parse::<T>().map_err(|_| format!("Invalid integer '{}'", s.trim()))
+        };
+        return match s.split_once('/') {
+            Some((numerator, denominator)) => {
+                let denominator = parse(denominator)?;
+                if denominator == T::default() {
+                    return Err(format!("Zero denominator in '{}'", s.trim()));
+                }
+                Ok(Rational::new(parse(numerator)?, denominator))
+            }
+            None => Ok(Rational::from(parse(s)?)),
+        };
+    }
+}
+
+// Largest r with r^2 <= n, correcting the floating point estimate of T::sqrt
+fn integer_sqrt<T: Integer>(n: T) -> T {
+    let mut root = n.sqrt();
+    while root.clone() * root.clone() > n {
+        root = root - T::one();
+    }
+    while (root.clone() + T::one()) * (root.clone() + T::one()) <= n {
+        root = root + T::one();
+    }
+    return root;
+}
+
+impl<T: Integer> Operations for Rational<T> {
+    fn abs(&self) -> Self {
+        return Rational { numerator: self.numerator.abs(), denominator: self.denominator.clone() };
+    }
+
+    // Exact, panics unless self is the square of a rational
+    fn sqrt(&self) -> Self {
+        if self.numerator < T::default() {
+            panic!("The square root of a negative rational is not supported");
+        }
+        // In lowest terms, so both parts have to be perfect squares
+        let numerator = integer_sqrt(self.numerator.clone());
+        let denominator = integer_sqrt(self.denominator.clone());
+        if numerator.clone() * numerator.clone() != self.numerator
+            || denominator.clone() * denominator.clone() != self.denominator
+        {
+            panic!("{} is not the square of a rational", self);
+        }
+        return Rational { numerator, denominator };
+    }
+
+    fn one() -> Self {
+        return Rational::from(T::one());
+    }
+
+    fn is_zero(&self) -> bool {
+        return self.numerator == T::default();
+    }
+}
+
+impl<T: Integer> Field for Rational<T> {}
+
+impl<T: Integer> Display for Rational<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.denominator == T::one() {
+            return write!(f, "{}", self.numerator);
+        }
+        return write!(f, "{}/{}", self.numerator, self.denominator);
+    }
+}
+
+impl<T: Integer> Default for Rational<T> {
+    fn default() -> Rational<T> {
+        return Rational::from(T::default());
+    }
+}
+
+impl<T: Integer> Add for Rational<T> {
+    type Output = Rational<T>;
+
+    fn add(self, q: Rational<T>) -> Rational<T> {
+        let gcd = T::gcd(self.denominator.clone(), q.denominator.clone());
+        return Rational::new(
+            self.numerator * (q.denominator.clone() / gcd.clone())
+                + q.numerator * (self.denominator.clone() / gcd.clone()),
+            self.denominator / gcd * q.denominator,
+        );
+    }
+}
+
+impl<T: Integer> Sub for Rational<T> {
+    type Output = Rational<T>;
+
+    fn sub(self, q: Rational<T>) -> Rational<T> {
+        return self + -q;
+    }
+}
+
+// Cross-cancels before multiplying to keep the intermediates small
+impl<T: Integer> Mul for Rational<T> {
+    type Output = Rational<T>;
+
+    fn mul(self, q: Rational<T>) -> Rational<T> {
+        if self.numerator == T::default() || q.numerator == T::default() {
+            return Rational::default();
+        }
+        let a = T::gcd(self.numerator.clone(), q.denominator.clone());
+        let b = T::gcd(q.numerator.clone(), self.denominator.clone());
+        return Rational::new(
+            (self.numerator / a.clone()) * (q.numerator / b.clone()),
+            (self.denominator / b) * (q.denominator / a),
+        );
+    }
+}
+
+impl<T: Integer> Div for Rational<T> {
+    type Output = Rational<T>;
+
+    fn div(self, q: Rational<T>) -> Rational<T> {
+        if q.numerator == T::default() {
+            panic!("Division of a rational by zero");
+        }
+        return self * Rational::new(q.denominator, q.numerator);
+    }
+}
+
+impl<T: Integer> Neg for Rational<T> {
+    type Output = Rational<T>;
+
+    fn neg(self) -> Rational<T> {
+        return Rational { numerator: -self.numerator, denominator: self.denominator };
+    }
+}
+
+// Both denominators are positive
+impl<T: Integer> PartialOrd for Rational<T> {
+    fn partial_cmp(&self, q: &Rational<T>) -> Option<std::cmp::Ordering> {
+        let left = self.numerator.clone() * q.denominator.clone();
+        return left.partial_cmp(&(q.numerator.clone() * self.denominator.clone()));
+    }
+}
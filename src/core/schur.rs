@@ -0,0 +1,204 @@
+use crate::core::matrix::Matrix;
+use crate::operations::{Field, Operations};
+
+// Real Schur form: original == u * t * u^T with u orthogonal and
+// t quasi upper triangular. Its 1x1 diagonal blocks are the real
+// eigenvalues, its 2x2 blocks the pairs of complex conjugate ones.
+pub struct Schur<K: std::fmt::Display> {
+    pub t: Matrix<K>,
+    pub u: Matrix<K>,
+    // (first row, size) of each diagonal block, from top to bottom
+    pub blocks: Vec<(usize, usize)>,
+}
+
+// Francis iterations allowed before each deflation
+const MAX_ITERATIONS: usize = 100;
+
+// x <- P x with P = I - 2 v v^T / (v^T v), on rows first..first + v.len()
+// of t, then x <- x P on the same columns of t and u, so that u t u^T is unchanged
+fn reflect<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+>(t: &mut [Vec<K>], u: &mut [Vec<K>], first: usize, x: &[K]) {
+    let zero = K::default();
+    let norm = x.iter().fold(zero, |s, &c| s + c * c).sqrt();
+    if norm == zero {
+        return;
+    }
+    // Reflect onto -sign(x0) * norm * e1 to avoid cancellation
+    let alpha = if x[0] > zero { -norm } else { norm };
+    let mut v = x.to_vec();
+    v[0] = v[0] - alpha;
+    let vv = v.iter().fold(zero, |s, &c| s + c * c);
+    if vv == zero {
+        return;
+    }
+    let scale = (K::one() + K::one()) / vv;
+
+    let n = t.len();
+    for j in 0..n {
+        let s = (0..v.len()).fold(zero, |s, i| s + v[i] * t[first + i][j]) * scale;
+        for i in 0..v.len() {
+            t[first + i][j] = t[first + i][j] - s * v[i];
+        }
+    }
+    for m in [t, u] {
+        for row in m.iter_mut() {
+            let s = (0..v.len()).fold(zero, |s, i| s + row[first + i] * v[i]) * scale;
+            for i in 0..v.len() {
+                row[first + i] = row[first + i] - s * v[i];
+            }
+        }
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+            + Field
+    > Matrix<K>
+{
+    // Householder reduction to Hessenberg form, then Francis double shift
+    // QR steps until every subdiagonal cell outside a 2x2 block is negligible.
+    // 2x2 blocks with real eigenvalues are split by one more rotation.
+    // Time: O(n^3) − Space: O(n^2)
+    pub fn schur(&self) -> Result<Schur<K>, &'static str> {
+        if !self.is_square() {
+            panic!("Shape {:?} is not a square", self.shape());
+        }
+        let n = self.shape().0;
+        let (zero, one) = (K::default(), K::one());
+        let mut t: Vec<Vec<K>> = (0..n).map(|r| (0..n).map(|c| self.get(r, c)).collect()).collect();
+        let mut u: Vec<Vec<K>> = (0..n)
+            .map(|r| (0..n).map(|c| if r == c { one } else { zero }).collect())
+            .collect();
+
+        // Hessenberg form
+        for k in 0..n.saturating_sub(2) {
+            let x: Vec<K> = (k + 1..n).map(|r| t[r][k]).collect();
+            reflect(&mut t, &mut u, k + 1, &x);
+            for row in t.iter_mut().skip(k + 2) {
+                row[k] = zero;
+            }
+        }
+
+        // A cell is negligible when adding it to its neighbours changes nothing
+        let norm = t.iter().flatten().fold(zero, |s, &c| s + c.abs());
+        let negligible = |t: &[Vec<K>], r: usize| {
+            let mut scale = t[r - 1][r - 1].abs() + t[r][r].abs();
+            if scale == zero {
+                scale = norm;
+            }
+            return scale + t[r][r - 1].abs() == scale;
+        };
+
+        let mut blocks: Vec<(usize, usize)> = Vec::new();
+        let mut end = n;
+        let mut iterations = 0;
+        while end > 0 {
+            // Start of the unreduced block ending at end - 1
+            let mut start = end - 1;
+            while start > 0 && !negligible(&t, start) {
+                start -= 1;
+            }
+            if start > 0 {
+                t[start][start - 1] = zero;
+            }
+
+            if start == end - 1 {
+                blocks.push((start, 1));
+                end -= 1;
+                iterations = 0;
+                continue;
+            }
+            if start == end - 2 {
+                let (a, b) = (t[start][start], t[start][end - 1]);
+                let (c, d) = (t[end - 1][start], t[end - 1][end - 1]);
+                let two = one + one;
+                let half = (a + d) / two;
+                let discriminant = (a - d) * (a - d) / (two * two) + b * c;
+                if discriminant < zero {
+                    blocks.push((start, 2));
+                } else {
+                    // Rotate the eigenvector of the larger eigenvalue onto the first axis
+                    let root = discriminant.sqrt();
+                    let lambda = if half >= zero { half + root } else { half - root };
+                    let (x, y) = if (lambda - d).abs() + c.abs() >= b.abs() + (lambda - a).abs() {
+                        (lambda - d, c)
+                    } else {
+                        (b, lambda - a)
+                    };
+                    // A rotation is a reflection followed by a sign flip,
+                    // the reflection alone triangularizes just as well
+                    reflect(&mut t, &mut u, start, &[x, y]);
+                    t[end - 1][start] = zero;
+                    blocks.push((end - 1, 1));
+                    blocks.push((start, 1));
+                }
+                end -= 2;
+                iterations = 0;
+                continue;
+            }
+
+            iterations += 1;
+            if iterations > MAX_ITERATIONS {
+                return Err("The QR algorithm did not converge");
+            }
+
+            // Sum and product of the two shifts, the eigenvalues of the
+            // trailing 2x2 block, or exceptional ones to break cycles
+            let (p, q) = (end - 2, end - 1);
+            let (mut sum, mut product) = (t[p][p] + t[q][q], t[p][p] * t[q][q] - t[p][q] * t[q][p]);
+            if iterations % 10 == 0 {
+                let s = t[q][p].abs() + t[p][p - 1].abs();
+                sum = (one + one + one) / (one + one) * s;
+                product = s * s;
+            }
+
+            // First column of (H - s1 I)(H - s2 I), then chase the bulge down
+            let l = start;
+            let mut x = vec![
+                t[l][l] * t[l][l] + t[l][l + 1] * t[l + 1][l] - sum * t[l][l] + product,
+                t[l + 1][l] * (t[l][l] + t[l + 1][l + 1] - sum),
+                t[l + 1][l] * t[l + 2][l + 1],
+            ];
+            for k in l..end - 1 {
+                let size = 3.min(end - k);
+                if k > l {
+                    x = (k..k + size).map(|r| t[r][k - 1]).collect();
+                }
+                reflect(&mut t, &mut u, k, &x[..size]);
+                if k > l {
+                    for row in t.iter_mut().skip(k + 1).take(size - 1) {
+                        row[k - 1] = zero;
+                    }
+                }
+            }
+        }
+
+        blocks.reverse();
+        return Ok(Schur {
+            t: Matrix::from(t),
+            u: Matrix::from(u),
+            blocks,
+        });
+    }
+}
@@ -0,0 +1,496 @@
+use std::{fmt, ops};
+use crate::core::matrix::Matrix;
+use crate::core::vector::Vector;
+use crate::operations::{Field, Operations};
+
+// Struct
+// Fixed-size matrix stored on the stack, in row-major order
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SMatrix<K, const R: usize, const C: usize> {
+    pub data: [[K; C]; R],
+}
+
+// Column vector
+pub type SVector<K, const N: usize> = SMatrix<K, N, 1>;
+
+// From 2D
+impl<K, const R: usize, const C: usize> From<[[K; C]; R]> for SMatrix<K, R, C> {
+    fn from(data: [[K; C]; R]) -> Self {
+        return SMatrix { data };
+    }
+}
+
+// From 1D
+impl<K: Copy, const N: usize> From<[K; N]> for SVector<K, N> {
+    fn from(data: [K; N]) -> Self {
+        return SMatrix { data: data.map(|x| [x]) };
+    }
+}
+
+// To dynamic
+impl<K: std::fmt::Display + Copy, const R: usize, const C: usize> From<SMatrix<K, R, C>>
+    for Matrix<K>
+{
+    fn from(m: SMatrix<K, R, C>) -> Self {
+        let mut data: Vec<K> = Vec::new();
+        // Matrix::from reads the data in column-major order
+        for c in 0..C {
+            for r in 0..R {
+                data.push(m.data[r][c]);
+            }
+        }
+        return Matrix::from((data, (R, C)));
+    }
+}
+
+impl<K: std::fmt::Display + Copy, const N: usize> From<SVector<K, N>> for Vector<K> {
+    fn from(v: SVector<K, N>) -> Self {
+        return Vector::from(v.data.map(|x| x[0]).to_vec());
+    }
+}
+
+// From dynamic
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations,
+        const R: usize,
+        const C: usize,
+    > TryFrom<&Matrix<K>> for SMatrix<K, R, C>
+{
+    type Error = &'static str;
+
+    fn try_from(m: &Matrix<K>) -> Result<Self, Self::Error> {
+        if m.shape() != (R, C) {
+            return Err("The shape of the matrix does not match");
+        }
+        let mut data = [[K::default(); C]; R];
+        for r in 0..R {
+            for c in 0..C {
+                data[r][c] = m.get(r, c);
+            }
+        }
+        return Ok(SMatrix { data });
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations,
+        const N: usize,
+    > TryFrom<&Vector<K>> for SVector<K, N>
+{
+    type Error = &'static str;
+
+    fn try_from(v: &Vector<K>) -> Result<Self, Self::Error> {
+        let flat = v.flat();
+        if flat.len() != N {
+            return Err("The size of the vector does not match");
+        }
+        let mut data = [[K::default(); 1]; N];
+        for i in 0..N {
+            data[i][0] = flat[i];
+        }
+        return Ok(SMatrix { data });
+    }
+}
+
+// Addition overload
+impl<K: ops::Add<Output = K> + Copy, const R: usize, const C: usize> ops::Add
+    for SMatrix<K, R, C>
+{
+    type Output = Self;
+
+    // Time: O(n) − Space: O(1)
+    // where n is the number of elements in the matrix
+    fn add(mut self, v: Self) -> Self {
+        for r in 0..R {
+            for c in 0..C {
+                self.data[r][c] = self.data[r][c] + v.data[r][c];
+            }
+        }
+        return self;
+    }
+}
+
+// Subtraction overload
+impl<K: ops::Sub<Output = K> + Copy, const R: usize, const C: usize> ops::Sub
+    for SMatrix<K, R, C>
+{
+    type Output = Self;
+
+    // Time: O(n) − Space: O(1)
+    // where n is the number of elements in the matrix
+    fn sub(mut self, v: Self) -> Self {
+        for r in 0..R {
+            for c in 0..C {
+                self.data[r][c] = self.data[r][c] - v.data[r][c];
+            }
+        }
+        return self;
+    }
+}
+
+// Scalar multiplication overload
+impl<K: ops::Mul<Output = K> + Copy, const R: usize, const C: usize> ops::Mul<K>
+    for SMatrix<K, R, C>
+{
+    type Output = Self;
+
+    // Time: O(n) − Space: O(1)
+    // where n is the number of elements in the matrix
+    fn mul(mut self, a: K) -> Self {
+        for r in 0..R {
+            for c in 0..C {
+                self.data[r][c] = self.data[r][c] * a;
+            }
+        }
+        return self;
+    }
+}
+
+// Matrix multiplication overload,
+// (R, C) * (C, P) is the only shape accepted by the compiler
+impl<
+        K: ops::Add<Output = K> + ops::Mul<Output = K> + Default + Copy,
+        const R: usize,
+        const C: usize,
+        const P: usize,
+    > ops::Mul<SMatrix<K, C, P>> for SMatrix<K, R, C>
+{
+    type Output = SMatrix<K, R, P>;
+
+    // Time: O(RCP) − Space: O(RP)
+    fn mul(self, m: SMatrix<K, C, P>) -> SMatrix<K, R, P> {
+        let mut data = [[K::default(); P]; R];
+        for r in 0..R {
+            for p in 0..P {
+                let mut sum = K::default();
+                for c in 0..C {
+                    sum = sum + self.data[r][c] * m.data[c][p];
+                }
+                data[r][p] = sum;
+            }
+        }
+        return SMatrix { data };
+    }
+}
+
+// Operations
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations,
+        const R: usize,
+        const C: usize,
+    > SMatrix<K, R, C>
+{
+    pub fn zeros() -> Self {
+        return SMatrix { data: [[K::default(); C]; R] };
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        return (R, C);
+    }
+
+    pub fn get(&self, r: usize, c: usize) -> K {
+        return self.data[r][c];
+    }
+
+    pub fn set(&mut self, r: usize, c: usize, value: K) {
+        self.data[r][c] = value;
+    }
+
+    // Time: O(RC) − Space: O(RC)
+    pub fn transpose(&self) -> SMatrix<K, C, R> {
+        let mut data = [[K::default(); R]; C];
+        for r in 0..R {
+            for c in 0..C {
+                data[c][r] = self.data[r][c];
+            }
+        }
+        return SMatrix { data };
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations,
+        const N: usize,
+    > SMatrix<K, N, N>
+{
+    pub fn identity() -> Self {
+        let mut data = [[K::default(); N]; N];
+        for i in 0..N {
+            data[i][i] = K::one();
+        }
+        return SMatrix { data };
+    }
+
+    // Time: O(N) − Space: O(1)
+    pub fn trace(&self) -> K {
+        let mut sum = K::default();
+        for i in 0..N {
+            sum = sum + self.data[i][i];
+        }
+        return sum;
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations,
+        const N: usize,
+    > SVector<K, N>
+{
+    // Time: O(N) − Space: O(1)
+    pub fn dot(&self, v: &SVector<K, N>) -> K {
+        let mut sum = K::default();
+        for i in 0..N {
+            sum = sum + self.data[i][0] * v.data[i][0];
+        }
+        return sum;
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > SMatrix<K, 2, 2>
+{
+    // Time: O(1) − Space: O(1)
+    pub fn determinant(&self) -> K {
+        let m = &self.data;
+        return m[0][0] * m[1][1] - m[0][1] * m[1][0];
+    }
+
+    // Time: O(1) − Space: O(1)
+    pub fn inverse(&self) -> Result<Self, &'static str>
+    where
+        K: Field,
+    {
+        let det = self.determinant();
+        if det == K::default() {
+            return Err("The matrix is singular");
+        }
+        let m = &self.data;
+        let inverse = SMatrix::from([
+            [m[1][1], -m[0][1]],
+            [-m[1][0], m[0][0]],
+        ]);
+        return Ok(inverse * (K::one() / det));
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > SMatrix<K, 3, 3>
+{
+    // Cofactor expansion along the first row
+    // Time: O(1) − Space: O(1)
+    pub fn determinant(&self) -> K {
+        let m = &self.data;
+        return m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0]);
+    }
+
+    // Adjugate divided by the determinant
+    // Time: O(1) − Space: O(1)
+    pub fn inverse(&self) -> Result<Self, &'static str>
+    where
+        K: Field,
+    {
+        let det = self.determinant();
+        if det == K::default() {
+            return Err("The matrix is singular");
+        }
+        let m = &self.data;
+        let adjugate = SMatrix::from([
+            [
+                m[1][1] * m[2][2] - m[1][2] * m[2][1],
+                m[0][2] * m[2][1] - m[0][1] * m[2][2],
+                m[0][1] * m[1][2] - m[0][2] * m[1][1],
+            ],
+            [
+                m[1][2] * m[2][0] - m[1][0] * m[2][2],
+                m[0][0] * m[2][2] - m[0][2] * m[2][0],
+                m[0][2] * m[1][0] - m[0][0] * m[1][2],
+            ],
+            [
+                m[1][0] * m[2][1] - m[1][1] * m[2][0],
+                m[0][1] * m[2][0] - m[0][0] * m[2][1],
+                m[0][0] * m[1][1] - m[0][1] * m[1][0],
+            ],
+        ]);
+        return Ok(adjugate * (K::one() / det));
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > SMatrix<K, 4, 4>
+{
+    // 2x2 minors of the two top rows (s) and of the two bottom rows (c),
+    // from which both the determinant and the adjugate are built
+    fn minors(&self) -> ([K; 6], [K; 6]) {
+        let m = &self.data;
+        let s = [
+            m[0][0] * m[1][1] - m[1][0] * m[0][1],
+            m[0][0] * m[1][2] - m[1][0] * m[0][2],
+            m[0][0] * m[1][3] - m[1][0] * m[0][3],
+            m[0][1] * m[1][2] - m[1][1] * m[0][2],
+            m[0][1] * m[1][3] - m[1][1] * m[0][3],
+            m[0][2] * m[1][3] - m[1][2] * m[0][3],
+        ];
+        let c = [
+            m[2][0] * m[3][1] - m[3][0] * m[2][1],
+            m[2][0] * m[3][2] - m[3][0] * m[2][2],
+            m[2][0] * m[3][3] - m[3][0] * m[2][3],
+            m[2][1] * m[3][2] - m[3][1] * m[2][2],
+            m[2][1] * m[3][3] - m[3][1] * m[2][3],
+            m[2][2] * m[3][3] - m[3][2] * m[2][3],
+        ];
+        return (s, c);
+    }
+
+    // Laplace expansion along the two top rows
+    // Time: O(1) − Space: O(1)
+    pub fn determinant(&self) -> K {
+        let (s, c) = self.minors();
+        return s[0] * c[5] - s[1] * c[4] + s[2] * c[3]
+            + s[3] * c[2] - s[4] * c[1] + s[5] * c[0];
+    }
+
+    // Time: O(1) − Space: O(1)
+    pub fn inverse(&self) -> Result<Self, &'static str>
+    where
+        K: Field,
+    {
+        let det = self.determinant();
+        if det == K::default() {
+            return Err("The matrix is singular");
+        }
+        let m = &self.data;
+        let (s, c) = self.minors();
+        let adjugate = SMatrix::from([
+            [
+                m[1][1] * c[5] - m[1][2] * c[4] + m[1][3] * c[3],
+                -m[0][1] * c[5] + m[0][2] * c[4] - m[0][3] * c[3],
+                m[3][1] * s[5] - m[3][2] * s[4] + m[3][3] * s[3],
+                -m[2][1] * s[5] + m[2][2] * s[4] - m[2][3] * s[3],
+            ],
+            [
+                -m[1][0] * c[5] + m[1][2] * c[2] - m[1][3] * c[1],
+                m[0][0] * c[5] - m[0][2] * c[2] + m[0][3] * c[1],
+                -m[3][0] * s[5] + m[3][2] * s[2] - m[3][3] * s[1],
+                m[2][0] * s[5] - m[2][2] * s[2] + m[2][3] * s[1],
+            ],
+            [
+                m[1][0] * c[4] - m[1][1] * c[2] + m[1][3] * c[0],
+                -m[0][0] * c[4] + m[0][1] * c[2] - m[0][3] * c[0],
+                m[3][0] * s[4] - m[3][1] * s[2] + m[3][3] * s[0],
+                -m[2][0] * s[4] + m[2][1] * s[2] - m[2][3] * s[0],
+            ],
+            [
+                -m[1][0] * c[3] + m[1][1] * c[1] - m[1][2] * c[0],
+                m[0][0] * c[3] - m[0][1] * c[1] + m[0][2] * c[0],
+                -m[3][0] * s[3] + m[3][1] * s[1] - m[3][2] * s[0],
+                m[2][0] * s[3] - m[2][1] * s[1] + m[2][2] * s[0],
+            ],
+        ]);
+        return Ok(adjugate * (K::one() / det));
+    }
+}
+
+// print! and println!
+impl<K: std::fmt::Display, const R: usize, const C: usize> fmt::Display for SMatrix<K, R, C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for r in 0..R {
+            write!(f, "[")?;
+            for c in 0..C {
+                write!(f, "{}", self.data[r][c])?;
+                if c < C - 1 {
+                    write!(f, ", ")?;
+                }
+            }
+            write!(f, "]")?;
+            if r < R - 1 {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
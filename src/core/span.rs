@@ -0,0 +1,201 @@
+use crate::core::matrix::Matrix;
+use crate::core::vector::Vector;
+use crate::operations::{Field, Operations};
+
+// Matrix of shape (n, k) whose columns are the k vectors of size n
+fn columns<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+        + Field
+>(
+    vectors: &[Vector<K>],
+    n: usize,
+) -> Matrix<K> {
+    let mut data: Vec<K> = Vec::new();
+    for v in vectors {
+        if v.shape().0 != n {
+            panic!(
+                "All vectors must have shape ({}, 1). Received {:?}.",
+                n,
+                v.shape()
+            );
+        }
+        // Matrix::from reads the data in column-major order
+        data.extend(v.flat());
+    }
+    return Matrix::from((data, (n, vectors.len())));
+}
+
+// Time: O(n * k^2) − Space: O(nk)
+// where vectors holds k vectors of size n
+pub fn is_linearly_independent<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+        + Field
+>(
+    vectors: &[Vector<K>],
+) -> bool {
+    if vectors.is_empty() {
+        return true;
+    }
+    let matrix = columns(vectors, vectors[0].shape().0);
+    return matrix.reduced_row_echelon_form().rank() == vectors.len();
+}
+
+// Coefficients a such that linear_combination(vectors, a) == v,
+// or None if v is not in the span of vectors.
+// Free coefficients (when vectors are dependent) are set to zero.
+// Time: O(n * k^2) − Space: O(nk)
+pub fn span_contains<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+        + Field
+>(
+    vectors: &[Vector<K>],
+    v: &Vector<K>,
+) -> Option<Vec<K>> {
+    let k = vectors.len();
+    let mut augmented: Vec<Vector<K>> = vectors.to_vec();
+    augmented.push(v.clone());
+    let rref = columns(&augmented, v.shape().0).reduced_row_echelon_form();
+
+    let mut coefficients: Vec<K> = vec![K::default(); k];
+    for &(r, c) in &rref.pivots {
+        // A pivot in the last column means 0 = 1
+        if c == k {
+            return None;
+        }
+        coefficients[c] = rref.matrix.get(r, k);
+    }
+
+    return Some(coefficients);
+}
+
+// Largest linearly independent subset of vectors, in their original order
+// Time: O(n * k^2) − Space: O(nk)
+pub fn extract_basis<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+        + Field
+>(
+    vectors: &[Vector<K>],
+) -> Vec<Vector<K>> {
+    if vectors.is_empty() {
+        return Vec::new();
+    }
+    let matrix = columns(vectors, vectors[0].shape().0);
+    return matrix
+        .reduced_row_echelon_form()
+        .pivot_columns()
+        .iter()
+        .map(|&c| vectors[c].clone())
+        .collect();
+}
+
+// Basis of K^n starting with extract_basis(vectors),
+// completed with vectors of the standard basis
+// Time: O(n^2 * (n + k)) − Space: O(n * (n + k))
+pub fn extend_to_basis<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+        + Field
+>(
+    vectors: &[Vector<K>],
+    n: usize,
+) -> Vec<Vector<K>> {
+    let mut candidates: Vec<Vector<K>> = vectors.to_vec();
+    for i in 0..n {
+        let mut e: Vec<K> = vec![K::default(); n];
+        e[i] = K::one();
+        candidates.push(Vector::from(e));
+    }
+    return extract_basis(&candidates);
+}
+
+// Matrix P such that P * [x]_from == [x]_to,
+// where [x]_b are the coordinates of x in the basis b
+// Time: O(n^3) − Space: O(n^2)
+pub fn change_of_basis<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+        + Field
+>(
+    from: &[Vector<K>],
+    to: &[Vector<K>],
+) -> Result<Matrix<K>, &'static str> {
+    let n = from.len();
+    if n == 0 || to.len() != n || from[0].shape().0 != n {
+        return Err("Both bases must hold n vectors of size n");
+    }
+    if !is_linearly_independent(from) || !is_linearly_independent(to) {
+        return Err("The vectors are not linearly independent");
+    }
+
+    // RREF of [to | from] is [I | P]
+    let mut augmented: Vec<Vector<K>> = to.to_vec();
+    augmented.extend_from_slice(from);
+    let rref = columns(&augmented, n).reduced_row_echelon_form();
+
+    let mut p: Matrix<K> = Matrix::from(vec![vec![K::default(); n]; n]);
+    for r in 0..n {
+        for c in 0..n {
+            p.set(r, c, rref.matrix.get(r, n + c));
+        }
+    }
+
+    return Ok(p);
+}
@@ -0,0 +1,424 @@
+use std::fmt;
+use crate::core::matrix::Matrix;
+use crate::core::vector::Vector;
+use crate::operations::Operations;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SparseFormat {
+    // Compressed sparse rows: pointers index rows, indices are columns
+    Csr,
+    // Compressed sparse columns: pointers index columns, indices are rows
+    Csc,
+}
+
+// Struct
+// Entries of the line (row or column) i are
+// values[pointers[i]..pointers[i + 1]], at positions indices[...]
+#[derive(Clone)]
+pub struct SparseMatrix<K> {
+    shape: (usize, usize),
+    format: SparseFormat,
+    pointers: Vec<usize>,
+    indices: Vec<usize>,
+    values: Vec<K>,
+}
+
+// Coordinate list, to build a SparseMatrix one entry at a time.
+// Duplicated entries are summed, the sums that are exactly zero are dropped.
+#[derive(Clone)]
+pub struct CooMatrix<K> {
+    shape: (usize, usize),
+    entries: Vec<(usize, usize, K)>,
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > CooMatrix<K>
+{
+    pub fn new(shape: (usize, usize)) -> CooMatrix<K> {
+        return CooMatrix {
+            shape,
+            entries: Vec::new(),
+        };
+    }
+
+    pub fn push(&mut self, r: usize, c: usize, value: K) {
+        if r >= self.shape.0 || c >= self.shape.1 {
+            panic!("Entry ({}, {}) is out of shape {:?}", r, c, self.shape);
+        }
+        self.entries.push((r, c, value));
+    }
+
+    // Time: O(e log e) − Space: O(e)
+    // where e is the number of entries pushed
+    pub fn to_csr(&self) -> SparseMatrix<K> {
+        return self.compress(SparseFormat::Csr);
+    }
+
+    // Time: O(e log e) − Space: O(e)
+    pub fn to_csc(&self) -> SparseMatrix<K> {
+        return self.compress(SparseFormat::Csc);
+    }
+
+    fn compress(&self, format: SparseFormat) -> SparseMatrix<K> {
+        // (line, position) in the compressed format
+        let mut entries: Vec<(usize, usize, K)> = self
+            .entries
+            .iter()
+            .map(|&(r, c, v)| match format {
+                SparseFormat::Csr => (r, c, v),
+                SparseFormat::Csc => (c, r, v),
+            })
+            .collect();
+        entries.sort_by_key(|&(line, position, _)| (line, position));
+
+        let lines = match format {
+            SparseFormat::Csr => self.shape.0,
+            SparseFormat::Csc => self.shape.1,
+        };
+        let mut pointers: Vec<usize> = vec![0; lines + 1];
+        let mut indices: Vec<usize> = Vec::new();
+        let mut values: Vec<K> = Vec::new();
+
+        let mut i = 0;
+        while i < entries.len() {
+            let (line, position, mut sum) = entries[i];
+            i += 1;
+            while i < entries.len() && (entries[i].0, entries[i].1) == (line, position) {
+                sum = sum + entries[i].2;
+                i += 1;
+            }
+            if sum != K::default() {
+                pointers[line + 1] += 1;
+                indices.push(position);
+                values.push(sum);
+            }
+        }
+        for line in 0..lines {
+            pointers[line + 1] += pointers[line];
+        }
+
+        return SparseMatrix {
+            shape: self.shape,
+            format,
+            pointers,
+            indices,
+            values,
+        };
+    }
+}
+
+// From dense
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > From<&Matrix<K>> for SparseMatrix<K>
+{
+    // Time: O(mn) − Space: O(nnz)
+    fn from(m: &Matrix<K>) -> Self {
+        let (rows, columns) = m.shape();
+        let mut coo: CooMatrix<K> = CooMatrix::new((rows, columns));
+        for r in 0..rows {
+            for c in 0..columns {
+                let value = m.get(r, c);
+                if value != K::default() {
+                    coo.push(r, c, value);
+                }
+            }
+        }
+        return coo.to_csr();
+    }
+}
+
+// To dense
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > From<&SparseMatrix<K>> for Matrix<K>
+{
+    // Time: O(mn) − Space: O(mn)
+    fn from(s: &SparseMatrix<K>) -> Self {
+        let (rows, columns) = s.shape;
+        // Matrix::from reads the data in column-major order
+        let mut data: Vec<K> = vec![K::default(); rows * columns];
+        s.for_each(|r, c, v| data[c * rows + r] = v);
+        return Matrix::from((data, (rows, columns)));
+    }
+}
+
+// Operations
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > SparseMatrix<K>
+{
+    pub fn shape(&self) -> (usize, usize) {
+        return self.shape;
+    }
+
+    pub fn format(&self) -> SparseFormat {
+        return self.format;
+    }
+
+    // Number of stored (non-zero) entries
+    pub fn nnz(&self) -> usize {
+        return self.values.len();
+    }
+
+    // Calls f(r, c, value) on every stored entry
+    // Time: O(nnz) − Space: O(1)
+    pub fn for_each<F: FnMut(usize, usize, K)>(&self, mut f: F) {
+        for line in 0..self.pointers.len() - 1 {
+            for i in self.pointers[line]..self.pointers[line + 1] {
+                match self.format {
+                    SparseFormat::Csr => f(line, self.indices[i], self.values[i]),
+                    SparseFormat::Csc => f(self.indices[i], line, self.values[i]),
+                }
+            }
+        }
+    }
+
+    // Time: O(log k) − Space: O(1)
+    // where k is the number of entries of the row (CSR) or column (CSC)
+    pub fn get(&self, r: usize, c: usize) -> K {
+        if r >= self.shape.0 || c >= self.shape.1 {
+            panic!("Entry ({}, {}) is out of shape {:?}", r, c, self.shape);
+        }
+        let (line, position) = match self.format {
+            SparseFormat::Csr => (r, c),
+            SparseFormat::Csc => (c, r),
+        };
+        let range = self.pointers[line]..self.pointers[line + 1];
+        return match self.indices[range.clone()].binary_search(&position) {
+            Ok(i) => self.values[range.start + i],
+            Err(_) => K::default(),
+        };
+    }
+
+    fn to_coo(&self) -> CooMatrix<K> {
+        let mut coo: CooMatrix<K> = CooMatrix::new(self.shape);
+        self.for_each(|r, c, v| coo.push(r, c, v));
+        return coo;
+    }
+
+    // Time: O(nnz log nnz) − Space: O(nnz)
+    pub fn to_csr(&self) -> SparseMatrix<K> {
+        if self.format == SparseFormat::Csr {
+            return self.clone();
+        }
+        return self.to_coo().to_csr();
+    }
+
+    // Time: O(nnz log nnz) − Space: O(nnz)
+    pub fn to_csc(&self) -> SparseMatrix<K> {
+        if self.format == SparseFormat::Csc {
+            return self.clone();
+        }
+        return self.to_coo().to_csc();
+    }
+
+    // The CSR arrays of A are the CSC arrays of A^T
+    // Time: O(nnz) − Space: O(nnz)
+    pub fn transpose(&self) -> SparseMatrix<K> {
+        return SparseMatrix {
+            shape: (self.shape.1, self.shape.0),
+            format: match self.format {
+                SparseFormat::Csr => SparseFormat::Csc,
+                SparseFormat::Csc => SparseFormat::Csr,
+            },
+            pointers: self.pointers.clone(),
+            indices: self.indices.clone(),
+            values: self.values.clone(),
+        };
+    }
+
+    // Time: O(nnz + m + n) − Space: O(m + n)
+    // where self is a matrix of shape (m, n)
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        if self.shape.1 != vec.shape().0 {
+            panic!(
+                "The vector shape {:?} is incompatible with the matrix shape {:?}",
+                vec.shape(),
+                self.shape,
+            );
+        }
+        let x = vec.flat();
+        let mut result: Vec<K> = vec![K::default(); self.shape.0];
+        self.for_each(|r, c, v| result[r] = result[r] + v * x[c]);
+        return Vector::from(result);
+    }
+
+    // Time: O(nnz * p + mp) − Space: O(mp)
+    // where mat is a matrix of shape (n, p)
+    pub fn mul_mat(&self, mat: &Matrix<K>) -> Matrix<K> {
+        if self.shape.1 != mat.shape().0 {
+            panic!(
+                "The matrix shape {:?} is incompatible with the matrix shape {:?}",
+                mat.shape(),
+                self.shape,
+            );
+        }
+        let (rows, p) = (self.shape.0, mat.shape().1);
+        let mut result: Matrix<K> = Matrix::from((vec![K::default(); rows * p], (rows, p)));
+        self.for_each(|r, c, v| {
+            for j in 0..p {
+                result.set(r, j, result.get(r, j) + v * mat.get(c, j));
+            }
+        });
+        return result;
+    }
+
+    // Coordinate format, "general" symmetry, 1-based indices
+    // Time: O(nnz) − Space: O(nnz)
+    pub fn to_matrix_market(&self) -> String {
+        let mut out = String::from("%%MatrixMarket matrix coordinate real general\n");
+        out += &format!("{} {} {}\n", self.shape.0, self.shape.1, self.nnz());
+        self.for_each(|r, c, v| out += &format!("{} {} {}\n", r + 1, c + 1, v));
+        return out;
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+            + std::str::FromStr
+    > SparseMatrix<K>
+{
+    // Reads the coordinate format, with real or integer values
+    // and general, symmetric or skew-symmetric storage
+    // Time: O(nnz log nnz) − Space: O(nnz)
+    pub fn from_matrix_market(source: &str) -> Result<SparseMatrix<K>, String> {
+        let mut lines = source.lines().enumerate();
+
+        let header = match lines.next() {
+            Some((_, header)) => header.to_lowercase(),
+            None => return Err(String::from("empty source")),
+        };
+        let banner: Vec<&str> = header.split_whitespace().collect();
+        if banner.len() != 5 || banner[0] != "%%matrixmarket" || banner[1] != "matrix" {
+            return Err(String::from("line 1: invalid banner"));
+        }
+        if banner[2] != "coordinate" {
+            return Err(String::from("line 1: only the coordinate format is supported"));
+        }
+        if banner[3] != "real" && banner[3] != "integer" {
+            return Err(String::from("line 1: only real and integer values are supported"));
+        }
+        let (symmetric, skew) = match banner[4] {
+            "general" => (false, false),
+            "symmetric" => (true, false),
+            "skew-symmetric" => (true, true),
+            _ => return Err(String::from("line 1: unsupported symmetry")),
+        };
+
+        let mut coo: Option<CooMatrix<K>> = None;
+        let mut expected: usize = 0;
+        let mut count: usize = 0;
+        for (number, line) in lines {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('%') {
+                continue;
+            }
+            let error = || format!("line {}: invalid entry", number + 1);
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+
+            match coo.as_mut() {
+                // Size line: rows, columns, entries
+                None => {
+                    let sizes: Vec<usize> = tokens
+                        .iter()
+                        .map(|t| t.parse::<usize>())
+                        .collect::<Result<_, _>>()
+                        .map_err(|_| format!("line {}: invalid size", number + 1))?;
+                    if sizes.len() != 3 {
+                        return Err(format!("line {}: invalid size", number + 1));
+                    }
+                    expected = sizes[2];
+                    coo = Some(CooMatrix::new((sizes[0], sizes[1])));
+                }
+                Some(coo) => {
+                    if tokens.len() != 3 {
+                        return Err(error());
+                    }
+                    let r = tokens[0].parse::<usize>().map_err(|_| error())?;
+                    let c = tokens[1].parse::<usize>().map_err(|_| error())?;
+                    let v = tokens[2].parse::<K>().map_err(|_| error())?;
+                    if r == 0 || c == 0 || r > coo.shape.0 || c > coo.shape.1 {
+                        return Err(format!("line {}: entry out of bounds", number + 1));
+                    }
+                    coo.push(r - 1, c - 1, v);
+                    if symmetric && r != c {
+                        coo.push(c - 1, r - 1, if skew { -v } else { v });
+                    }
+                    count += 1;
+                }
+            }
+        }
+
+        return match coo {
+            None => Err(String::from("missing size line")),
+            Some(_) if count != expected => Err(format!(
+                "expected {} entries, found {}",
+                expected, count
+            )),
+            Some(coo) => Ok(coo.to_csr()),
+        };
+    }
+}
+
+// print! and println!
+impl<K: std::fmt::Display> fmt::Display for SparseMatrix<K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:?} {}x{} with {} entries",
+            self.format, self.shape.0, self.shape.1, self.values.len()
+        )
+    }
+}
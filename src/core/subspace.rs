@@ -0,0 +1,87 @@
+use crate::core::matrix::Matrix;
+use crate::core::vector::Vector;
+use crate::operations::{Field, Operations};
+
+// The four fundamental subspaces, built from the pivots of the RREF.
+// Pivots are exact for exact scalars, and compared with a threshold
+// relative to their column for floating points (see Matrix::rank).
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+            + Field
+    > Matrix<K>
+{
+    // Basis of { x | Ax = 0 }, one vector per free column
+    // Time: O(m^2 * n + n^2) − Space: O(mn + n^2)
+    // where self is a matrix of shape (m, n)
+    pub fn null_space(&self) -> Vec<Vector<K>> {
+        let rref = self.reduced_row_echelon_form();
+        let columns = self.shape().1;
+
+        let mut basis: Vec<Vector<K>> = Vec::new();
+        for &free in &rref.free_columns {
+            let mut x: Vec<K> = vec![K::default(); columns];
+            x[free] = K::one();
+            for &(r, c) in &rref.pivots {
+                x[c] = -rref.matrix.get(r, free);
+            }
+            basis.push(Vector::from(x));
+        }
+
+        return basis;
+    }
+
+    // Basis of the image of A: the columns of A holding a pivot
+    // Time: O(m^2 * n) − Space: O(mn)
+    pub fn column_space(&self) -> Vec<Vector<K>> {
+        let rref = self.reduced_row_echelon_form();
+        let rows = self.shape().0;
+
+        let mut basis: Vec<Vector<K>> = Vec::new();
+        for c in rref.pivot_columns() {
+            let column: Vec<K> = (0..rows).map(|r| self.get(r, c)).collect();
+            basis.push(Vector::from(column));
+        }
+
+        return basis;
+    }
+
+    // Basis of the span of the rows: the non-zero rows of the RREF
+    // Time: O(m^2 * n) − Space: O(mn)
+    pub fn row_space(&self) -> Vec<Vector<K>> {
+        let rref = self.reduced_row_echelon_form();
+        let columns = self.shape().1;
+
+        let mut basis: Vec<Vector<K>> = Vec::new();
+        for &(r, _) in &rref.pivots {
+            let row: Vec<K> = (0..columns).map(|c| rref.matrix.get(r, c)).collect();
+            basis.push(Vector::from(row));
+        }
+
+        return basis;
+    }
+
+    // Basis of { y | A^T y = 0 }
+    // Time: O(n^2 * m + m^2) − Space: O(mn + m^2)
+    pub fn left_null_space(&self) -> Vec<Vector<K>> {
+        let mut transposed = self.clone();
+        transposed.transpose();
+        return transposed.null_space();
+    }
+
+    // Dimension of the null space, from the same elimination as rank,
+    // so that rank + nullity == n
+    // Time: O(m^2 * n) − Space: O(mn)
+    pub fn nullity(&self) -> usize {
+        return self.shape().1 - self.rank();
+    }
+}
@@ -0,0 +1,228 @@
+use crate::core::matrix::Matrix;
+use crate::core::vector::Vector;
+use crate::operations::{Field, Operations};
+
+// Up to this many unknowns, the equations are solved through their
+// Kronecker form directly instead of the Schur decompositions
+const KRONECKER_LIMIT: usize = 16;
+
+const NOT_UNIQUE: &str = "The equation has no unique solution";
+
+// Transpose as a new matrix
+fn transposed<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+>(m: &Matrix<K>) -> Matrix<K> {
+    let mut t = m.clone();
+    t.transpose();
+    return t;
+}
+
+// The unique solution of a small square system, or NOT_UNIQUE
+fn solve_unique<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+        + Field
+>(m: &Matrix<K>, b: Vec<K>) -> Result<Vec<K>, &'static str> {
+    return match m.inverse() {
+        Ok(inverse) => Ok(inverse.mul_vec(Vector::from(b)).flat()),
+        Err(_) => Err(NOT_UNIQUE),
+    };
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+            + Field
+    > Matrix<K>
+{
+    // X with self * X + X * b = c, unique when self and -b share no eigenvalue.
+    // Bartels-Stewart: with self = U R U^T and b = V S V^T in real Schur form,
+    // R Y + Y S = U^T c V is solved one block of Y at a time, left to right
+    // and bottom to top, and X = U Y V^T.
+    // Time: O(m^3 + n^3 + mn(m + n)) − Space: O(m^2 + n^2)
+    // where self is a matrix of shape (m, m) and b of shape (n, n)
+    pub fn solve_sylvester(&self, b: &Matrix<K>, c: &Matrix<K>) -> Result<Matrix<K>, &'static str> {
+        if !self.is_square() || !b.is_square() || c.shape() != (self.shape().0, b.shape().0) {
+            panic!(
+                "Shapes {:?}, {:?} and {:?} are incompatible",
+                self.shape(),
+                b.shape(),
+                c.shape()
+            );
+        }
+        let (m, n) = (self.shape().0, b.shape().0);
+        if m * n <= KRONECKER_LIMIT {
+            // (I ⊗ A + B^T ⊗ I) vec(X) = vec(C)
+            let system = transposed(b).kronecker_sum(self);
+            let x = solve_unique(&system, c.vec().flat())?;
+            return Ok(Matrix::unvec(&Vector::from(x), (m, n)));
+        }
+
+        let (a_schur, b_schur) = (self.schur()?, b.schur()?);
+        let (r, s) = (&a_schur.t, &b_schur.t);
+        let f = transposed(&a_schur.u).mul_mat(c.clone()).mul_mat(b_schur.u.clone());
+        let mut y: Matrix<K> = Matrix::from(vec![vec![K::default(); n]; m]);
+
+        for &(j0, bj) in &b_schur.blocks {
+            for &(i0, bi) in a_schur.blocks.iter().rev() {
+                // R_ii Y_ij + Y_ij S_jj = F_ij - (R Y)_ij - (Y S)_ij over known blocks
+                let mut rhs: Vec<K> = Vec::new();
+                for jj in j0..j0 + bj {
+                    for ii in i0..i0 + bi {
+                        let mut cell = f.get(ii, jj);
+                        for l in i0 + bi..m {
+                            cell = cell - r.get(ii, l) * y.get(l, jj);
+                        }
+                        for k in 0..j0 {
+                            cell = cell - y.get(ii, k) * s.get(k, jj);
+                        }
+                        rhs.push(cell);
+                    }
+                }
+
+                // Column-major unknowns, I ⊗ R_ii + S_jj^T ⊗ I
+                let mut system: Matrix<K> = Matrix::from(vec![vec![K::default(); bi * bj]; bi * bj]);
+                for jj in 0..bj {
+                    for ii in 0..bi {
+                        for kk in 0..bi {
+                            let cell = system.get(jj * bi + ii, jj * bi + kk);
+                            system.set(jj * bi + ii, jj * bi + kk, cell + r.get(i0 + ii, i0 + kk));
+                        }
+                        for kk in 0..bj {
+                            let cell = system.get(jj * bi + ii, kk * bi + ii);
+                            system.set(jj * bi + ii, kk * bi + ii, cell + s.get(j0 + kk, j0 + jj));
+                        }
+                    }
+                }
+                let block = solve_unique(&system, rhs)?;
+                for jj in 0..bj {
+                    for ii in 0..bi {
+                        y.set(i0 + ii, j0 + jj, block[jj * bi + ii]);
+                    }
+                }
+            }
+        }
+
+        return Ok(a_schur.u.mul_mat(y).mul_mat(transposed(&b_schur.u)));
+    }
+
+    // X with self * X + X * self^T + q = 0, the controllability
+    // or observability Gramian when self is stable
+    // Time: as solve_sylvester − Space: O(n^2)
+    pub fn solve_lyapunov(&self, q: &Matrix<K>) -> Result<Matrix<K>, &'static str> {
+        return self.solve_sylvester(&transposed(self), &(q.clone() * -K::one()));
+    }
+
+    // X with self * X * self^T - X + q = 0 (Stein equation),
+    // unique when no two eigenvalues of self multiply to 1.
+    // With self = U R U^T, R Y R^T - Y = -U^T q U is solved one block of Y
+    // at a time from the bottom right, and X = U Y U^T.
+    // Time: O(n^3) − Space: O(n^2)
+    pub fn solve_discrete_lyapunov(&self, q: &Matrix<K>) -> Result<Matrix<K>, &'static str> {
+        if !self.is_square() || q.shape() != self.shape() {
+            panic!("Shapes {:?} and {:?} are incompatible", self.shape(), q.shape());
+        }
+        let n = self.shape().0;
+        if n * n <= KRONECKER_LIMIT {
+            // (A ⊗ A - I) vec(X) = -vec(Q)
+            let mut system = self.kronecker(self);
+            for i in 0..n * n {
+                system.set(i, i, system.get(i, i) - K::one());
+            }
+            let rhs: Vec<K> = q.vec().flat().iter().map(|&c| -c).collect();
+            let x = solve_unique(&system, rhs)?;
+            return Ok(Matrix::unvec(&Vector::from(x), (n, n)));
+        }
+
+        let schur = self.schur()?;
+        let r = &schur.t;
+        let f = transposed(&schur.u).mul_mat(q.clone()).mul_mat(schur.u.clone());
+        let mut y: Matrix<K> = Matrix::from(vec![vec![K::default(); n]; n]);
+        // z = y * r^T, filled in for every finished block row of y
+        let mut z: Matrix<K> = Matrix::from(vec![vec![K::default(); n]; n]);
+
+        for &(i0, bi) in schur.blocks.iter().rev() {
+            for &(j0, bj) in schur.blocks.iter().rev() {
+                // R_ii Y_ij R_jj^T - Y_ij = -F_ij - (R Y R^T)_ij over known blocks
+                let mut rhs: Vec<K> = Vec::new();
+                for jj in j0..j0 + bj {
+                    for ii in i0..i0 + bi {
+                        let mut cell = -f.get(ii, jj);
+                        for k in i0 + bi..n {
+                            cell = cell - r.get(ii, k) * z.get(k, jj);
+                        }
+                        for k in i0..i0 + bi {
+                            let mut known = K::default();
+                            for l in j0 + bj..n {
+                                known = known + y.get(k, l) * r.get(jj, l);
+                            }
+                            cell = cell - r.get(ii, k) * known;
+                        }
+                        rhs.push(cell);
+                    }
+                }
+
+                // Column-major unknowns, R_jj ⊗ R_ii - I
+                let mut system: Matrix<K> = Matrix::from(vec![vec![K::default(); bi * bj]; bi * bj]);
+                for jj in 0..bj {
+                    for ii in 0..bi {
+                        for ll in 0..bj {
+                            for kk in 0..bi {
+                                let cell = r.get(i0 + ii, i0 + kk) * r.get(j0 + jj, j0 + ll);
+                                system.set(jj * bi + ii, ll * bi + kk, cell);
+                            }
+                        }
+                        let diagonal = system.get(jj * bi + ii, jj * bi + ii);
+                        system.set(jj * bi + ii, jj * bi + ii, diagonal - K::one());
+                    }
+                }
+                let block = solve_unique(&system, rhs)?;
+                for jj in 0..bj {
+                    for ii in 0..bi {
+                        y.set(i0 + ii, j0 + jj, block[jj * bi + ii]);
+                    }
+                }
+            }
+
+            for k in i0..i0 + bi {
+                for j in 0..n {
+                    let mut cell = K::default();
+                    for l in 0..n {
+                        cell = cell + y.get(k, l) * r.get(j, l);
+                    }
+                    z.set(k, j, cell);
+                }
+            }
+        }
+
+        return Ok(schur.u.mul_mat(y).mul_mat(transposed(&schur.u)));
+    }
+}
@@ -0,0 +1,423 @@
+use std::fmt;
+use crate::core::matrix::Matrix;
+use crate::core::triangular::{Diagonal, LowerTriangular};
+use crate::core::vector::Vector;
+use crate::operations::{Field, Operations};
+
+// Symmetric square matrix, only the upper triangle
+// (n(n + 1) / 2 cells) is stored, row after row
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymmetricMatrix<K> {
+    size: usize,
+    data: Vec<K>,
+}
+
+// Cyclic Jacobi eigenvalue algorithm on a small symmetric matrix:
+// its eigenvalues and the columns of its eigenvectors
+pub(crate) fn symmetric_eigen<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+>(mut t: Vec<Vec<K>>, tolerance: K) -> (Vec<K>, Vec<Vec<K>>) {
+    let m = t.len();
+    let one = K::one();
+    let mut vectors: Vec<Vec<K>> = vec![vec![K::default(); m]; m];
+    for i in 0..m {
+        vectors[i][i] = one;
+    }
+
+    for _ in 0..100 {
+        let (mut off, mut total) = (K::default(), K::default());
+        for p in 0..m {
+            for q in 0..m {
+                total = total + t[p][q] * t[p][q];
+                if p != q {
+                    off = off + t[p][q] * t[p][q];
+                }
+            }
+        }
+        if off <= tolerance * tolerance * total {
+            break;
+        }
+
+        for p in 0..m {
+            for q in p + 1..m {
+                if t[p][q] == K::default() {
+                    continue;
+                }
+                // Rotation in the (p, q) plane that zeroes t[p][q]
+                let theta = (t[q][q] - t[p][p]) / ((one + one) * t[p][q]);
+                let mut tan = one / (theta.abs() + (theta * theta + one).sqrt());
+                if theta < K::default() {
+                    tan = -tan;
+                }
+                let cos = one / (tan * tan + one).sqrt();
+                let sin = tan * cos;
+                for r in 0..m {
+                    let (x, y) = (t[r][p], t[r][q]);
+                    t[r][p] = cos * x - sin * y;
+                    t[r][q] = sin * x + cos * y;
+                }
+                for r in 0..m {
+                    let (x, y) = (t[p][r], t[q][r]);
+                    t[p][r] = cos * x - sin * y;
+                    t[q][r] = sin * x + cos * y;
+                }
+                for r in 0..m {
+                    let (x, y) = (vectors[r][p], vectors[r][q]);
+                    vectors[r][p] = cos * x - sin * y;
+                    vectors[r][q] = sin * x + cos * y;
+                }
+            }
+        }
+    }
+
+    return ((0..m).map(|i| t[i][i]).collect(), vectors);
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > SymmetricMatrix<K>
+{
+    pub fn new(size: usize) -> SymmetricMatrix<K> {
+        return SymmetricMatrix {
+            size,
+            data: vec![K::default(); size * (size + 1) / 2],
+        };
+    }
+
+    pub fn size(&self) -> usize {
+        return self.size;
+    }
+
+    // Cell (r, c) of the upper triangle, r <= c
+    fn index(&self, r: usize, c: usize) -> usize {
+        let (r, c) = if r <= c { (r, c) } else { (c, r) };
+        return r * self.size - r * (r.saturating_sub(1)) / 2 + (c - r);
+    }
+
+    pub fn get(&self, r: usize, c: usize) -> K {
+        if r >= self.size || c >= self.size {
+            panic!("Cell ({}, {}) is out of bounds for size {}", r, c, self.size);
+        }
+        return self.data[self.index(r, c)];
+    }
+
+    // Sets both (r, c) and (c, r)
+    pub fn set(&mut self, r: usize, c: usize, value: K) {
+        if r >= self.size || c >= self.size {
+            panic!("Cell ({}, {}) is out of bounds for size {}", r, c, self.size);
+        }
+        let index = self.index(r, c);
+        self.data[index] = value;
+    }
+
+    // Time: O(n^2) − Space: O(n)
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        let v = vec.flat();
+        if v.len() != self.size {
+            panic!("Sizes {} and {} are incompatible", self.size, v.len());
+        }
+        let result: Vec<K> = (0..self.size)
+            .map(|r| (0..self.size).fold(K::default(), |s, c| s + self.get(r, c) * v[c]))
+            .collect();
+        return Vector::from(result);
+    }
+
+    // self <- self + alpha * x * x^T, which stays symmetric
+    // Time: O(n^2) − Space: O(n)
+    pub fn rank_one_update(&mut self, alpha: K, x: &Vector<K>) {
+        let x = x.flat();
+        if x.len() != self.size {
+            panic!("Sizes {} and {} are incompatible", self.size, x.len());
+        }
+        for r in 0..self.size {
+            let scaled = alpha * x[r];
+            for c in r..self.size {
+                let index = self.index(r, c);
+                self.data[index] = self.data[index] + scaled * x[c];
+            }
+        }
+    }
+
+    // self <- self + alpha * x * x^T for x of shape (n, k),
+    // the sum of the rank-one updates of its columns
+    // Time: O(n^2 * k) − Space: O(1)
+    pub fn rank_k_update(&mut self, alpha: K, x: &Matrix<K>) {
+        let (rows, k) = x.shape();
+        if rows != self.size {
+            panic!("Sizes {} and {:?} are incompatible", self.size, x.shape());
+        }
+        for r in 0..self.size {
+            for c in r..self.size {
+                let dot = (0..k).fold(K::default(), |s, j| s + x.get(r, j) * x.get(c, j));
+                let index = self.index(r, c);
+                self.data[index] = self.data[index] + alpha * dot;
+            }
+        }
+    }
+
+    // L lower triangular with a positive diagonal and self = L * L^T,
+    // which exists exactly when self is positive definite.
+    // Meant for floating points: it needs an order and square roots,
+    // which is why solve and determinant only use it for them
+    // Time: O(n^3 / 3) − Space: O(n^2 / 2)
+    pub fn cholesky(&self) -> Result<LowerTriangular<K>, &'static str>
+    where
+        K: Field,
+    {
+        let mut l: LowerTriangular<K> = LowerTriangular::new(self.size);
+        for j in 0..self.size {
+            let mut pivot = self.get(j, j);
+            for k in 0..j {
+                pivot = pivot - l.get(j, k) * l.get(j, k);
+            }
+            if pivot <= K::default() {
+                return Err("The matrix is not positive definite");
+            }
+            let pivot = pivot.sqrt();
+            l.set(j, j, pivot);
+            for i in j + 1..self.size {
+                let mut cell = self.get(i, j);
+                for k in 0..j {
+                    cell = cell - l.get(i, k) * l.get(j, k);
+                }
+                l.set(i, j, cell / pivot);
+            }
+        }
+        return Ok(l);
+    }
+
+    // L unit lower triangular and D diagonal with self = L * D * L^T.
+    // Free of square roots, so exact over rationals and finite fields too.
+    // Exists when no leading minor is zero, e.g. for positive definite matrices,
+    // but without pivoting a tiny pivot ruins floating points
+    // Time: O(n^3 / 3) − Space: O(n^2 / 2)
+    pub fn ldlt(&self) -> Result<(LowerTriangular<K>, Diagonal<K>), &'static str>
+    where
+        K: Field,
+    {
+        let mut l: LowerTriangular<K> = LowerTriangular::new(self.size);
+        let mut d: Vec<K> = Vec::with_capacity(self.size);
+        for j in 0..self.size {
+            let mut pivot = self.get(j, j);
+            for k in 0..j {
+                pivot = pivot - l.get(j, k) * l.get(j, k) * d[k];
+            }
+            if pivot == K::default() {
+                return Err("The elimination met a zero pivot");
+            }
+            l.set(j, j, K::one());
+            for i in j + 1..self.size {
+                let mut cell = self.get(i, j);
+                for k in 0..j {
+                    cell = cell - l.get(i, k) * l.get(j, k) * d[k];
+                }
+                l.set(i, j, cell / pivot);
+            }
+            d.push(pivot);
+        }
+        return Ok((l, Diagonal::from(d)));
+    }
+
+    // Floating points: L L^T x = b when positive definite, whose pivots
+    // are bounded by the diagonal, the dense pivoted elimination otherwise.
+    // Exact scalars: L D L^T x = b when no leading minor is zero,
+    // the dense elimination otherwise
+    // Time: O(n^3) − Space: O(n^2)
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>, &'static str>
+    where
+        K: Field,
+    {
+        if b.shape().0 != self.size {
+            return Err("The right-hand side does not match the matrix");
+        }
+        if K::epsilon() != K::default() {
+            return match self.cholesky() {
+                Ok(l) => l.transpose().solve(&l.solve(b)?),
+                Err(_) => Matrix::from(self).solve(b),
+            };
+        }
+        return match self.ldlt() {
+            Ok((l, d)) => l.transpose().solve(&d.solve(&l.solve(b)?)?),
+            Err(_) => Matrix::from(self).solve(b),
+        };
+    }
+
+    // Floating points: det(L)^2 when positive definite,
+    // exact scalars: the product of D when no leading minor is zero,
+    // the dense determinant otherwise
+    // Time: O(n^3) − Space: O(n^2)
+    pub fn determinant(&self) -> K
+    where
+        K: Field,
+    {
+        if K::epsilon() != K::default() {
+            return match self.cholesky() {
+                Ok(l) => l.determinant() * l.determinant(),
+                Err(_) => Matrix::from(self).determinant(),
+            };
+        }
+        return match self.ldlt() {
+            Ok((_, d)) => d.determinant(),
+            Err(_) => Matrix::from(self).determinant(),
+        };
+    }
+
+    // Eigenvalues in ascending order and the matching orthonormal
+    // eigenvectors as columns, by the cyclic Jacobi method, which stops
+    // once the off-diagonal norm is below tolerance times the total norm
+    // Time: O(n^3) per sweep − Space: O(n^2)
+    pub fn eigen(&self, tolerance: K) -> (Vec<K>, Matrix<K>) {
+        let n = self.size;
+        let t: Vec<Vec<K>> = (0..n).map(|r| (0..n).map(|c| self.get(r, c)).collect()).collect();
+        let (values, vectors) = symmetric_eigen(t, tolerance);
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&a, &b| values[a].partial_cmp(&values[b]).unwrap_or(std::cmp::Ordering::Equal));
+        let sorted: Vec<K> = order.iter().map(|&i| values[i]).collect();
+        let columns: Vec<Vec<K>> = (0..n).map(|r| order.iter().map(|&i| vectors[r][i]).collect()).collect();
+        return (sorted, Matrix::from(columns));
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > Matrix<K>
+{
+    // Exact comparison: floating points off by some rounding are not symmetric.
+    // Matrix::solve, determinant and the eigen routines do not dispatch on it:
+    // the dense pivoted routines are what the packed ones fall back on,
+    // and SymmetricMatrix::try_from is the explicit way in
+    // Time: O(n^2) − Space: O(1)
+    pub fn is_symmetric(&self) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+        let n = self.shape().0;
+        return (0..n).all(|r| (r + 1..n).all(|c| self.get(r, c) == self.get(c, r)));
+    }
+
+    // self^T == -self, so the diagonal is zero
+    // Time: O(n^2) − Space: O(1)
+    pub fn is_skew_symmetric(&self) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+        let n = self.shape().0;
+        return (0..n).all(|r| (r..n).all(|c| self.get(r, c) == -self.get(c, r)));
+    }
+
+    // Cholesky factor, after checking the structure
+    // Time: O(n^3 / 3) − Space: O(n^2)
+    pub fn cholesky(&self) -> Result<LowerTriangular<K>, &'static str>
+    where
+        K: Field,
+    {
+        return SymmetricMatrix::try_from(self)?.cholesky();
+    }
+}
+
+// To dense
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > From<&SymmetricMatrix<K>> for Matrix<K>
+{
+    // Time: O(n^2) − Space: O(n^2)
+    fn from(s: &SymmetricMatrix<K>) -> Self {
+        let n = s.size;
+        let rows: Vec<Vec<K>> = (0..n).map(|r| (0..n).map(|c| s.get(r, c)).collect()).collect();
+        return Matrix::from(rows);
+    }
+}
+
+// From dense, refused unless the matrix is symmetric
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > TryFrom<&Matrix<K>> for SymmetricMatrix<K>
+{
+    type Error = &'static str;
+
+    // Time: O(n^2) − Space: O(n^2)
+    fn try_from(m: &Matrix<K>) -> Result<Self, Self::Error> {
+        if !m.is_symmetric() {
+            return Err("The matrix is not symmetric");
+        }
+        let n = m.shape().0;
+        let mut s: SymmetricMatrix<K> = SymmetricMatrix::new(n);
+        for r in 0..n {
+            for c in r..n {
+                s.set(r, c, m.get(r, c));
+            }
+        }
+        return Ok(s);
+    }
+}
+
+// print! and println!, as the dense matrix
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > fmt::Display for SymmetricMatrix<K>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Matrix::from(self))
+    }
+}
@@ -0,0 +1,297 @@
+use std::fmt;
+use colored::Colorize;
+use crate::core::matrix::Matrix;
+use crate::operations::Operations;
+
+// Elementary row operation, rows are 0-indexed
+// (they are displayed 1-indexed, as in R1, R2, ...)
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum RowOperation<K> {
+    // R_a <-> R_b
+    Swap { a: usize, b: usize },
+    // R_row <- factor * R_row
+    Scale { row: usize, factor: K },
+    // R_target <- R_target - factor * R_source
+    Eliminate { target: usize, source: usize, factor: K },
+}
+
+// A single step of a row reduction
+pub struct RowStep<K: std::fmt::Display> {
+    pub operation: RowOperation<K>,
+    pub elementary: Matrix<K>,
+    pub result: Matrix<K>,
+}
+
+// Every step taken to reduce `initial`
+pub struct RowTrace<K: std::fmt::Display> {
+    pub initial: Matrix<K>,
+    pub steps: Vec<RowStep<K>>,
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Clone
+            + Operations
+    > RowOperation<K>
+{
+    pub fn rows(&self) -> Vec<usize> {
+        return match *self {
+            RowOperation::Swap { a, b } => vec![a, b],
+            RowOperation::Scale { row, .. } => vec![row],
+            RowOperation::Eliminate { target, .. } => vec![target],
+        };
+    }
+
+    // Time: O(n) − Space: O(1)
+    // where n is the number of columns of the matrix
+    pub fn apply(&self, matrix: &mut Matrix<K>) {
+        let columns = matrix.shape().1;
+
+        match self.clone() {
+            RowOperation::Swap { a, b } => {
+                for c in 0..columns {
+                    let temp = matrix.get(a, c);
+                    matrix.set(a, c, matrix.get(b, c));
+                    matrix.set(b, c, temp);
+                }
+            }
+            RowOperation::Scale { row, factor } => {
+                for c in 0..columns {
+                    matrix.set(row, c, matrix.get(row, c) * factor.clone());
+                }
+            }
+            RowOperation::Eliminate { target, source, factor } => {
+                for c in 0..columns {
+                    matrix.set(
+                        target, c,
+                        matrix.get(target, c) - factor.clone() * matrix.get(source, c)
+                    );
+                }
+            }
+        }
+    }
+
+    // The matrix E such that E * A applies the operation to A
+    // Time: O(n^2) − Space: O(n^2)
+    // where n is the number of rows of A
+    pub fn elementary(&self, n: usize) -> Matrix<K> {
+        let mut e: Matrix<K> = Matrix::from(vec![vec![K::default(); n]; n]);
+        for i in 0..n {
+            e.set(i, i, K::one());
+        }
+
+        match self.clone() {
+            RowOperation::Swap { a, b } => {
+                e.set(a, a, K::default());
+                e.set(b, b, K::default());
+                e.set(a, b, K::one());
+                e.set(b, a, K::one());
+            }
+            RowOperation::Scale { row, factor } => {
+                e.set(row, row, factor);
+            }
+            RowOperation::Eliminate { target, source, factor } => {
+                e.set(target, source, -factor);
+            }
+        }
+
+        return e;
+    }
+
+    pub fn latex(&self) -> String {
+        return match self.clone() {
+            RowOperation::Swap { a, b } => {
+                format!("R_{{{}}} \\leftrightarrow R_{{{}}}", a + 1, b + 1)
+            }
+            RowOperation::Scale { row, factor } => {
+                format!("R_{{{}}} \\to {} R_{{{}}}", row + 1, factor, row + 1)
+            }
+            RowOperation::Eliminate { target, source, factor } => {
+                let (sign, factor) = signed(factor);
+                format!(
+                    "R_{{{}}} \\to R_{{{}}} {} {} R_{{{}}}",
+                    target + 1, target + 1, sign, factor, source + 1
+                )
+            }
+        };
+    }
+}
+
+// Splits a subtracted factor into its sign and magnitude,
+// so that "- -2" is written "+ 2"
+fn signed<
+    K: std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Clone
+>(factor: K) -> (&'static str, K) {
+    if factor < K::default() {
+        return ("+", -factor);
+    }
+    return ("-", factor);
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Clone
+            + Operations
+    > RowStep<K>
+{
+    // Records `operation`, `result` being the matrix right after it was applied
+    // Time: O(n^2) − Space: O(n^2)
+    // where n is the number of rows of the matrix
+    pub fn new(operation: RowOperation<K>, result: &Matrix<K>) -> RowStep<K> {
+        let elementary = operation.elementary(result.shape().0);
+        return RowStep {
+            operation,
+            elementary,
+            result: result.clone(),
+        };
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Clone
+            + Operations
+    > RowTrace<K>
+{
+    // The reduced matrix (the initial one if no step was needed)
+    pub fn result(&self) -> Matrix<K> {
+        return match self.steps.last() {
+            Some(step) => step.result.clone(),
+            None => self.initial.clone(),
+        };
+    }
+
+    // The product of all the elementary matrices,
+    // i.e. E such that E * initial == result
+    // Time: O(s * n^3) − Space: O(n^2)
+    // where s is the number of steps and n the number of rows
+    pub fn transform(&self) -> Matrix<K> {
+        let n = self.initial.shape().0;
+        let mut e: Matrix<K> = Matrix::from(vec![vec![K::default(); n]; n]);
+        for i in 0..n {
+            e.set(i, i, K::one());
+        }
+        for step in &self.steps {
+            e = step.elementary.mul_mat(e);
+        }
+        return e;
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut out = format!("{}\n", self.initial);
+        for (i, step) in self.steps.iter().enumerate() {
+            out += &format!(
+                "\nStep {}: {}\nE =\n{}\n=>\n{}\n",
+                i + 1, step.operation, step.elementary, step.result
+            );
+        }
+        return out;
+    }
+
+    // Same as to_text, with the operations and the rows they touched highlighted
+    pub fn to_colored(&self) -> String {
+        let mut out = format!("{}\n", self.initial);
+        for (i, step) in self.steps.iter().enumerate() {
+            let touched = step.operation.rows();
+            out += &format!(
+                "\n{} {}\n{}\n{}\n{}\n",
+                format!("Step {}:", i + 1).bold(),
+                step.operation.to_string().yellow(),
+                "E =".dimmed(),
+                step.elementary.to_string().dimmed(),
+                "=>".bold(),
+            );
+            for (r, line) in step.result.to_string().lines().enumerate() {
+                if touched.contains(&r) {
+                    out += &format!("{}\n", line.green());
+                } else {
+                    out += &format!("{}\n", line);
+                }
+            }
+        }
+        return out;
+    }
+
+    // \begin{bmatrix} ... \end{bmatrix} \xrightarrow{R_1 \leftrightarrow R_2} ...
+    pub fn to_latex(&self) -> String {
+        let mut out = latex_matrix(&self.initial);
+        for step in &self.steps {
+            out += &format!(
+                "\n\\xrightarrow{{{}}}\n{}",
+                step.operation.latex(),
+                latex_matrix(&step.result)
+            );
+        }
+        return out;
+    }
+}
+
+fn latex_matrix<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Clone
+        + Operations
+>(matrix: &Matrix<K>) -> String {
+    let (rows, columns) = matrix.shape();
+    let mut out = String::from("\\begin{bmatrix}\n");
+    for r in 0..rows {
+        let row: Vec<String> = (0..columns)
+            .map(|c| matrix.get(r, c).to_string())
+            .collect();
+        out += &format!("{} \\\\\n", row.join(" & "));
+    }
+    out += "\\end{bmatrix}";
+    return out;
+}
+
+// print! and println!
+impl<K: std::fmt::Display + Clone + std::cmp::PartialOrd + std::ops::Neg<Output = K> + Default>
+    fmt::Display for RowOperation<K>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.clone() {
+            RowOperation::Swap { a, b } => write!(f, "R{} <-> R{}", a + 1, b + 1),
+            RowOperation::Scale { row, factor } => {
+                write!(f, "R{} <- {} * R{}", row + 1, factor, row + 1)
+            }
+            RowOperation::Eliminate { target, source, factor } => {
+                let (sign, factor) = signed(factor);
+                write!(f, "R{} <- R{} {} {} * R{}", target + 1, target + 1, sign, factor, source + 1)
+            }
+        }
+    }
+}
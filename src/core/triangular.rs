@@ -0,0 +1,551 @@
+use std::fmt;
+use crate::core::matrix::Matrix;
+use crate::core::vector::Vector;
+use crate::operations::{Field, Operations};
+
+// Square matrix with zeros below the diagonal,
+// only the n(n + 1) / 2 other cells are stored, row after row
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpperTriangular<K> {
+    size: usize,
+    data: Vec<K>,
+}
+
+// Square matrix with zeros above the diagonal,
+// only the n(n + 1) / 2 other cells are stored, row after row
+#[derive(Debug, Clone, PartialEq)]
+pub struct LowerTriangular<K> {
+    size: usize,
+    data: Vec<K>,
+}
+
+// Square matrix with zeros outside the diagonal
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagonal<K> {
+    data: Vec<K>,
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > UpperTriangular<K>
+{
+    pub fn new(size: usize) -> UpperTriangular<K> {
+        return UpperTriangular {
+            size,
+            data: vec![K::default(); size * (size + 1) / 2],
+        };
+    }
+
+    pub fn size(&self) -> usize {
+        return self.size;
+    }
+
+    // Row r starts after the r previous rows of n, n - 1, ... cells
+    fn index(&self, r: usize, c: usize) -> usize {
+        return r * self.size - r * (r.saturating_sub(1)) / 2 + (c - r);
+    }
+
+    pub fn get(&self, r: usize, c: usize) -> K {
+        if r >= self.size || c >= self.size {
+            panic!("Cell ({}, {}) is out of bounds for size {}", r, c, self.size);
+        }
+        if r > c {
+            return K::default();
+        }
+        return self.data[self.index(r, c)];
+    }
+
+    pub fn set(&mut self, r: usize, c: usize, value: K) {
+        if r >= self.size || c >= self.size || r > c {
+            panic!("Cell ({}, {}) is not stored in an upper triangular matrix", r, c);
+        }
+        let index = self.index(r, c);
+        self.data[index] = value;
+    }
+
+    // Product of the diagonal
+    // Time: O(n) − Space: O(1)
+    pub fn determinant(&self) -> K {
+        return (0..self.size).fold(K::one(), |d, i| d * self.get(i, i));
+    }
+
+    // Time: O(n^2) − Space: O(n)
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        let v = vec.flat();
+        if v.len() != self.size {
+            panic!("Sizes {} and {} are incompatible", self.size, v.len());
+        }
+        let result: Vec<K> = (0..self.size)
+            .map(|r| (r..self.size).fold(K::default(), |s, c| s + self.get(r, c) * v[c]))
+            .collect();
+        return Vector::from(result);
+    }
+
+    // Back substitution, from the last row up
+    // Time: O(n^2) − Space: O(n)
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>, &'static str>
+    where
+        K: Field,
+    {
+        let b = b.flat();
+        if b.len() != self.size {
+            return Err("The right-hand side does not match the matrix");
+        }
+        let mut x: Vec<K> = vec![K::default(); self.size];
+        for r in (0..self.size).rev() {
+            let pivot = self.get(r, r);
+            if pivot == K::default() {
+                return Err("The matrix is singular");
+            }
+            let sum = (r + 1..self.size).fold(K::default(), |s, c| s + self.get(r, c) * x[c]);
+            x[r] = (b[r] - sum) / pivot;
+        }
+        return Ok(Vector::from(x));
+    }
+
+    // Same storage read column after column
+    // Time: O(n^2) − Space: O(n^2)
+    pub fn transpose(&self) -> LowerTriangular<K> {
+        let mut transposed: LowerTriangular<K> = LowerTriangular::new(self.size);
+        for r in 0..self.size {
+            for c in r..self.size {
+                transposed.set(c, r, self.get(r, c));
+            }
+        }
+        return transposed;
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > LowerTriangular<K>
+{
+    pub fn new(size: usize) -> LowerTriangular<K> {
+        return LowerTriangular {
+            size,
+            data: vec![K::default(); size * (size + 1) / 2],
+        };
+    }
+
+    pub fn size(&self) -> usize {
+        return self.size;
+    }
+
+    // Row r starts after the r previous rows of 1, 2, ... cells
+    fn index(&self, r: usize, c: usize) -> usize {
+        return r * (r + 1) / 2 + c;
+    }
+
+    pub fn get(&self, r: usize, c: usize) -> K {
+        if r >= self.size || c >= self.size {
+            panic!("Cell ({}, {}) is out of bounds for size {}", r, c, self.size);
+        }
+        if c > r {
+            return K::default();
+        }
+        return self.data[self.index(r, c)];
+    }
+
+    pub fn set(&mut self, r: usize, c: usize, value: K) {
+        if r >= self.size || c >= self.size || c > r {
+            panic!("Cell ({}, {}) is not stored in a lower triangular matrix", r, c);
+        }
+        let index = self.index(r, c);
+        self.data[index] = value;
+    }
+
+    // Product of the diagonal
+    // Time: O(n) − Space: O(1)
+    pub fn determinant(&self) -> K {
+        return (0..self.size).fold(K::one(), |d, i| d * self.get(i, i));
+    }
+
+    // Time: O(n^2) − Space: O(n)
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        let v = vec.flat();
+        if v.len() != self.size {
+            panic!("Sizes {} and {} are incompatible", self.size, v.len());
+        }
+        let result: Vec<K> = (0..self.size)
+            .map(|r| (0..=r).fold(K::default(), |s, c| s + self.get(r, c) * v[c]))
+            .collect();
+        return Vector::from(result);
+    }
+
+    // Forward substitution, from the first row down
+    // Time: O(n^2) − Space: O(n)
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>, &'static str>
+    where
+        K: Field,
+    {
+        let b = b.flat();
+        if b.len() != self.size {
+            return Err("The right-hand side does not match the matrix");
+        }
+        let mut x: Vec<K> = vec![K::default(); self.size];
+        for r in 0..self.size {
+            let pivot = self.get(r, r);
+            if pivot == K::default() {
+                return Err("The matrix is singular");
+            }
+            let sum = (0..r).fold(K::default(), |s, c| s + self.get(r, c) * x[c]);
+            x[r] = (b[r] - sum) / pivot;
+        }
+        return Ok(Vector::from(x));
+    }
+
+    // Time: O(n^2) − Space: O(n^2)
+    pub fn transpose(&self) -> UpperTriangular<K> {
+        let mut transposed: UpperTriangular<K> = UpperTriangular::new(self.size);
+        for r in 0..self.size {
+            for c in 0..=r {
+                transposed.set(c, r, self.get(r, c));
+            }
+        }
+        return transposed;
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > Diagonal<K>
+{
+    pub fn size(&self) -> usize {
+        return self.data.len();
+    }
+
+    pub fn get(&self, r: usize, c: usize) -> K {
+        if r >= self.size() || c >= self.size() {
+            panic!("Cell ({}, {}) is out of bounds for size {}", r, c, self.size());
+        }
+        if r != c {
+            return K::default();
+        }
+        return self.data[r];
+    }
+
+    pub fn diagonal(&self) -> &[K] {
+        return &self.data;
+    }
+
+    // Product of the diagonal
+    // Time: O(n) − Space: O(1)
+    pub fn determinant(&self) -> K {
+        return self.data.iter().fold(K::one(), |d, &x| d * x);
+    }
+
+    // Time: O(n) − Space: O(n)
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        let v = vec.flat();
+        if v.len() != self.size() {
+            panic!("Sizes {} and {} are incompatible", self.size(), v.len());
+        }
+        return Vector::from(self.data.iter().zip(v).map(|(&d, x)| d * x).collect::<Vec<K>>());
+    }
+
+    // Scales row i of mat by the i-th diagonal cell
+    // Time: O(mn) − Space: O(mn)
+    pub fn mul_mat(&self, mat: &Matrix<K>) -> Matrix<K> {
+        if mat.shape().0 != self.size() {
+            panic!("Sizes {} and {:?} are incompatible", self.size(), mat.shape());
+        }
+        let mut product = mat.clone();
+        for r in 0..mat.shape().0 {
+            for c in 0..mat.shape().1 {
+                product.set(r, c, self.data[r] * mat.get(r, c));
+            }
+        }
+        return product;
+    }
+
+    // Time: O(n) − Space: O(n)
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>, &'static str>
+    where
+        K: Field,
+    {
+        let b = b.flat();
+        if b.len() != self.size() {
+            return Err("The right-hand side does not match the matrix");
+        }
+        if self.data.iter().any(|&d| d == K::default()) {
+            return Err("The matrix is singular");
+        }
+        return Ok(Vector::from(self.data.iter().zip(b).map(|(&d, x)| x / d).collect::<Vec<K>>()));
+    }
+
+    // Time: O(n) − Space: O(n)
+    pub fn inverse(&self) -> Result<Diagonal<K>, &'static str>
+    where
+        K: Field,
+    {
+        if self.data.iter().any(|&d| d == K::default()) {
+            return Err("The matrix is singular");
+        }
+        return Ok(Diagonal::from(self.data.iter().map(|&d| K::one() / d).collect::<Vec<K>>()));
+    }
+}
+
+impl<K: Copy> From<Vec<K>> for Diagonal<K> {
+    fn from(data: Vec<K>) -> Self {
+        return Diagonal { data };
+    }
+}
+
+// To dense
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > From<&UpperTriangular<K>> for Matrix<K>
+{
+    // Time: O(n^2) − Space: O(n^2)
+    fn from(t: &UpperTriangular<K>) -> Self {
+        let rows: Vec<Vec<K>> = (0..t.size).map(|r| (0..t.size).map(|c| t.get(r, c)).collect()).collect();
+        return Matrix::from(rows);
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > From<&LowerTriangular<K>> for Matrix<K>
+{
+    // Time: O(n^2) − Space: O(n^2)
+    fn from(t: &LowerTriangular<K>) -> Self {
+        let rows: Vec<Vec<K>> = (0..t.size).map(|r| (0..t.size).map(|c| t.get(r, c)).collect()).collect();
+        return Matrix::from(rows);
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > From<&Diagonal<K>> for Matrix<K>
+{
+    // Time: O(n^2) − Space: O(n^2)
+    fn from(d: &Diagonal<K>) -> Self {
+        let n = d.size();
+        let rows: Vec<Vec<K>> = (0..n).map(|r| (0..n).map(|c| d.get(r, c)).collect()).collect();
+        return Matrix::from(rows);
+    }
+}
+
+// From dense, refused when a cell outside the structure is not zero
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > TryFrom<&Matrix<K>> for UpperTriangular<K>
+{
+    type Error = &'static str;
+
+    // Time: O(n^2) − Space: O(n^2)
+    fn try_from(m: &Matrix<K>) -> Result<Self, Self::Error> {
+        if !m.is_square() {
+            return Err("The matrix is not square");
+        }
+        let n = m.shape().0;
+        let mut t: UpperTriangular<K> = UpperTriangular::new(n);
+        for r in 0..n {
+            for c in 0..n {
+                if c >= r {
+                    t.set(r, c, m.get(r, c));
+                } else if m.get(r, c) != K::default() {
+                    return Err("The matrix has non-zero cells below its diagonal");
+                }
+            }
+        }
+        return Ok(t);
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > TryFrom<&Matrix<K>> for LowerTriangular<K>
+{
+    type Error = &'static str;
+
+    // Time: O(n^2) − Space: O(n^2)
+    fn try_from(m: &Matrix<K>) -> Result<Self, Self::Error> {
+        if !m.is_square() {
+            return Err("The matrix is not square");
+        }
+        let n = m.shape().0;
+        let mut t: LowerTriangular<K> = LowerTriangular::new(n);
+        for r in 0..n {
+            for c in 0..n {
+                if c <= r {
+                    t.set(r, c, m.get(r, c));
+                } else if m.get(r, c) != K::default() {
+                    return Err("The matrix has non-zero cells above its diagonal");
+                }
+            }
+        }
+        return Ok(t);
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > TryFrom<&Matrix<K>> for Diagonal<K>
+{
+    type Error = &'static str;
+
+    // Time: O(n^2) − Space: O(n)
+    fn try_from(m: &Matrix<K>) -> Result<Self, Self::Error> {
+        if !m.is_square() {
+            return Err("The matrix is not square");
+        }
+        let n = m.shape().0;
+        for r in 0..n {
+            for c in 0..n {
+                if r != c && m.get(r, c) != K::default() {
+                    return Err("The matrix has non-zero cells outside its diagonal");
+                }
+            }
+        }
+        return Ok(Diagonal::from((0..n).map(|i| m.get(i, i)).collect::<Vec<K>>()));
+    }
+}
+
+// print! and println!, as the dense matrix
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > fmt::Display for UpperTriangular<K>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Matrix::from(self))
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > fmt::Display for LowerTriangular<K>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Matrix::from(self))
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > fmt::Display for Diagonal<K>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Matrix::from(self))
+    }
+}
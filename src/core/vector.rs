@@ -8,7 +8,7 @@ pub struct Vector<K: std::fmt::Display> {
 }
 
 // From 1D
-impl<K: std::fmt::Display + Copy> From<Vec<K>> for Vector<K> {
+impl<K: std::fmt::Display + Clone> From<Vec<K>> for Vector<K> {
     fn from(data: Vec<K>) -> Self {
         return Vector {
             matrix: Matrix::from((data.clone(), (data.len(), 1))),
@@ -17,7 +17,7 @@ impl<K: std::fmt::Display + Copy> From<Vec<K>> for Vector<K> {
 }
 
 // Clone
-impl<K: std::fmt::Display + Copy> Clone for Vector<K> {
+impl<K: std::fmt::Display + Clone> Clone for Vector<K> {
     fn clone(&self) -> Self {
         return Vector {
             matrix: self.matrix.clone(),
@@ -26,7 +26,7 @@ impl<K: std::fmt::Display + Copy> Clone for Vector<K> {
 }
 
 // Addition overload
-impl<K: std::fmt::Display + ops::Add<Output = K> + Copy> ops::Add for Vector<K> {
+impl<K: std::fmt::Display + ops::Add<Output = K> + Clone> ops::Add for Vector<K> {
     type Output = Self;
 
     // Time: O(n) − Space: O(n)
@@ -38,7 +38,7 @@ impl<K: std::fmt::Display + ops::Add<Output = K> + Copy> ops::Add for Vector<K>
 }
 
 // Subtraction overload
-impl<K: std::fmt::Display + ops::Sub<Output = K> + Copy> ops::Sub for Vector<K> {
+impl<K: std::fmt::Display + ops::Sub<Output = K> + Clone> ops::Sub for Vector<K> {
     type Output = Self;
 
     // Time: O(n) − Space: O(n)
@@ -50,7 +50,7 @@ impl<K: std::fmt::Display + ops::Sub<Output = K> + Copy> ops::Sub for Vector<K>
 }
 
 // Scalar multiplication overload
-impl<K: std::fmt::Display + ops::Mul<Output = K> + Copy> ops::Mul<K> for Vector<K> {
+impl<K: std::fmt::Display + ops::Mul<Output = K> + Clone> ops::Mul<K> for Vector<K> {
     type Output = Self;
 
     // Time: O(n) − Space: O(n)
@@ -72,7 +72,7 @@ impl<
             + std::cmp::PartialOrd
             + std::ops::Neg<Output = K>
             + Default
-            + Copy
+            + Clone
             + Operations
     > Vector<K>
 {
@@ -122,7 +122,7 @@ impl<
 
         let mut sum: K = K::default();
         for i in 0..self.flat().len() {
-            sum = sum + u[i] * v[i];
+            sum = sum + u[i].clone() * v[i].clone();
         }
 
         return sum;
@@ -160,6 +160,253 @@ impl<
         }
         return max;
     }
+
+    fn check_shape(&self, v: &Vector<K>) -> Result<(), &'static str> {
+        if self.shape() != v.shape() {
+            return Err("The vectors have incompatible shapes");
+        }
+        return Ok(());
+    }
+
+    fn check_3d(&self, v: &Vector<K>) -> Result<(), &'static str> {
+        if self.shape().0 != 3 || v.shape().0 != 3 {
+            return Err("Both vectors must have shape (3, 1)");
+        }
+        return Ok(());
+    }
+
+    // Time: O(n) − Space: O(n)
+    pub fn normalize(&self) -> Result<Vector<K>, &'static str> {
+        let norm = self.norm();
+        if norm == K::default() {
+            return Err("The zero vector cannot be normalized");
+        }
+        let mut u = self.clone();
+        u.scl(K::one() / norm);
+        return Ok(u);
+    }
+
+    // Time: O(n) − Space: O(1)
+    pub fn angle_cos(&self, v: &Vector<K>) -> Result<K, &'static str> {
+        self.check_shape(v)?;
+        let norms = self.norm() * v.norm();
+        if norms == K::default() {
+            return Err("The angle with the zero vector is undefined");
+        }
+        return Ok(self.dot(v.clone()) / norms);
+    }
+
+    // Component of self along v
+    // Time: O(n) − Space: O(n)
+    pub fn project_onto(&self, v: &Vector<K>) -> Result<Vector<K>, &'static str> {
+        self.check_shape(v)?;
+        let squared = v.dot(v.clone());
+        if squared == K::default() {
+            return Err("Cannot project onto the zero vector");
+        }
+        let mut projection = v.clone();
+        projection.scl(self.dot(v.clone()) / squared);
+        return Ok(projection);
+    }
+
+    // Component of self orthogonal to v
+    // Time: O(n) − Space: O(n)
+    pub fn reject_from(&self, v: &Vector<K>) -> Result<Vector<K>, &'static str> {
+        let mut rejection = self.clone();
+        rejection.sub(&self.project_onto(v)?);
+        return Ok(rejection);
+    }
+
+    // Mirror image of self across the hyperplane orthogonal to normal
+    // Time: O(n) − Space: O(n)
+    pub fn reflect(&self, normal: &Vector<K>) -> Result<Vector<K>, &'static str> {
+        let mut projection = self.project_onto(normal)?;
+        projection.scl(K::one() + K::one());
+        let mut reflection = self.clone();
+        reflection.sub(&projection);
+        return Ok(reflection);
+    }
+
+    // Euclidian distance
+    // Time: O(n) − Space: O(n)
+    pub fn distance(&self, v: &Vector<K>) -> Result<K, &'static str> {
+        self.check_shape(v)?;
+        let mut difference = self.clone();
+        difference.sub(v);
+        return Ok(difference.norm());
+    }
+
+    // self * v^T
+    // Time: O(nm) − Space: O(nm)
+    // where self has shape (n, 1) and v has shape (m, 1)
+    pub fn outer(&self, v: &Vector<K>) -> Matrix<K> {
+        let u = self.flat();
+        let v = v.flat();
+        let mut data: Vec<K> = Vec::new();
+        // Matrix::from reads the data in column-major order
+        for c in 0..v.len() {
+            for r in 0..u.len() {
+                data.push(u[r].clone() * v[c].clone());
+            }
+        }
+        return Matrix::from((data, (u.len(), v.len())));
+    }
+
+    // Time: O(1) − Space: O(1)
+    pub fn cross(&self, v: &Vector<K>) -> Result<Vector<K>, &'static str> {
+        self.check_3d(v)?;
+        let a = self.flat();
+        let b = v.flat();
+
+        return Ok(Vector::from(vec![
+            a[1].clone() * b[2].clone() - a[2].clone() * b[1].clone(),
+            a[2].clone() * b[0].clone() - a[0].clone() * b[2].clone(),
+            a[0].clone() * b[1].clone() - a[1].clone() * b[0].clone()
+        ]));
+    }
+
+    // self . (v x w), the signed volume of the parallelepiped
+    // Time: O(1) − Space: O(1)
+    pub fn triple_product(&self, v: &Vector<K>, w: &Vector<K>) -> Result<K, &'static str> {
+        self.check_3d(v)?;
+        return Ok(self.dot(v.cross(w)?));
+    }
+
+    // self x (v x w)
+    // Time: O(1) − Space: O(1)
+    pub fn vector_triple_product(
+        &self,
+        v: &Vector<K>,
+        w: &Vector<K>,
+    ) -> Result<Vector<K>, &'static str> {
+        return self.cross(&v.cross(w)?);
+    }
+}
+
+// Methods relying on trigonometry
+macro_rules! impl_vector_angles {
+    ($t:ty) => {
+        impl Vector<$t> {
+            // Time: O(n) − Space: O(1)
+            pub fn angle(&self, v: &Vector<$t>) -> Result<$t, &'static str> {
+                return Ok(self.angle_cos(v)?.clamp(-1., 1.).acos());
+            }
+
+            // Spherical interpolation between two unit vectors,
+            // falling back to lerp when they are (anti)parallel
+            // Time: O(n) − Space: O(n)
+            pub fn slerp(&self, v: &Vector<$t>, t: $t) -> Result<Vector<$t>, &'static str> {
+                let theta = self.angle(v)?;
+                let sin = theta.sin();
+                let (a_weight, b_weight) = if sin.is_zero() {
+                    (1. - t, t)
+                } else {
+                    (((1. - t) * theta).sin() / sin, (t * theta).sin() / sin)
+                };
+                let mut a = self.clone();
+                a.scl(a_weight);
+                let mut b = v.clone();
+                b.scl(b_weight);
+                a.add(&b);
+                return Ok(a);
+            }
+        }
+    };
+}
+
+impl_vector_angles!(f32);
+impl_vector_angles!(f64);
+
+// Time: O(nk) − Space: O(n)
+// where v holds k vectors of size n
+pub fn linear_combination<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Clone
+        + Operations
+>(
+    v: &[Vector<K>],
+    a: &[K],
+) -> Result<Vector<K>, &'static str> {
+    if v.len() != a.len() {
+        return Err("There must be as many coefficients as vectors");
+    }
+    if v.is_empty() {
+        return Err("At least one vector is needed");
+    }
+
+    let mut accum = v[0].clone();
+    accum.scl(a[0].clone());
+    for i in 1..v.len() {
+        v[0].check_shape(&v[i])?;
+        let mut current = v[i].clone();
+        current.scl(a[i].clone());
+        accum.add(&current);
+    }
+
+    return Ok(accum);
+}
+
+// Time: O(n) − Space: O(n)
+// where n is the size of u and v
+pub fn lerp<
+    V: std::fmt::Display
+        + std::ops::Add<Output = V>
+        + std::ops::Sub<Output = V>
+        + std::ops::Mul<f32, Output = V>
+>(
+    u: V,
+    v: V,
+    t: f32,
+) -> V {
+    return u * (1. - t) + v * t;
+}
+
+// Time: O(n) − Space: O(1)
+pub fn angle_cos<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Clone
+        + Operations
+>(
+    u: &Vector<K>,
+    v: &Vector<K>,
+) -> Result<K, &'static str> {
+    return u.angle_cos(v);
+}
+
+// Time: O(1) − Space: O(1)
+pub fn cross_product<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Clone
+        + Operations
+>(
+    u: &Vector<K>,
+    v: &Vector<K>,
+) -> Result<Vector<K>, &'static str> {
+    return u.cross(v);
 }
 
 // print! and println!
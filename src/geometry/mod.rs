@@ -0,0 +1,3 @@
+pub mod predicates;
+
+pub use predicates::*;
@@ -0,0 +1,246 @@
+// Determinant-based predicates on points given as vectors.
+// Signs are exact for integer and rational coordinates. Floating points
+// only report a degenerate case on an exactly zero determinant, whatever
+// the scale of the coordinates, so rounding can tip a near one either way.
+use crate::core::{Matrix, Vector};
+use crate::operations::Operations;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Orientation {
+    // Counter-clockwise in 2D, right-handed in 3D
+    Positive,
+    // Clockwise in 2D, left-handed in 3D
+    Negative,
+    // Collinear in 2D, coplanar in 3D
+    Degenerate,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+// Rows p_i - p_0, one per point after the first
+fn edges<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+>(points: &[&Vector<K>]) -> Matrix<K> {
+    let dimension = points[0].shape().0;
+    if points.len() != dimension + 1 {
+        panic!(
+            "A simplex in dimension {} needs {} points, not {}",
+            dimension,
+            dimension + 1,
+            points.len()
+        );
+    }
+    let origin = points[0].flat();
+    let rows: Vec<Vec<K>> = points[1..]
+        .iter()
+        .map(|p| {
+            if p.shape().0 != dimension {
+                panic!("Points of dimensions {} and {} are mixed", dimension, p.shape().0);
+            }
+            p.flat().iter().zip(&origin).map(|(&x, &o)| x - o).collect()
+        })
+        .collect();
+    return Matrix::from(rows);
+}
+
+fn sign<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+>(determinant: K) -> Orientation {
+    if determinant == K::default() {
+        return Orientation::Degenerate;
+    }
+    if determinant > K::default() {
+        return Orientation::Positive;
+    }
+    return Orientation::Negative;
+}
+
+// n! * signed volume of the simplex p_0, ..., p_n in dimension n,
+// det(p_1 - p_0, ..., p_n - p_0), which is exact for integer coordinates
+// Time: O(n^3) − Space: O(n^2)
+pub fn simplex_determinant<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+>(points: &[&Vector<K>]) -> K {
+    return edges(points).determinant();
+}
+
+// Signed volume of the simplex, positive when its points are positively oriented.
+// The division by n! truncates for integer coordinates.
+// Time: O(n^3) − Space: O(n^2)
+pub fn simplex_volume<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+>(points: &[&Vector<K>]) -> K {
+    let mut factorial = K::one();
+    let mut k = K::one();
+    for _ in 1..points.len() - 1 {
+        k = k + K::one();
+        factorial = factorial * k;
+    }
+    return simplex_determinant(points) / factorial;
+}
+
+// Signed area of the triangle abc, positive when counter-clockwise
+pub fn signed_area<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+>(a: &Vector<K>, b: &Vector<K>, c: &Vector<K>) -> K {
+    return simplex_volume(&[a, b, c]);
+}
+
+// Signed volume of the tetrahedron abcd, positive when
+// (b - a, c - a, d - a) is right-handed
+pub fn signed_volume<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+>(a: &Vector<K>, b: &Vector<K>, c: &Vector<K>, d: &Vector<K>) -> K {
+    return simplex_volume(&[a, b, c, d]);
+}
+
+// Whether c is to the left of (Positive), to the right of (Negative)
+// or on (Degenerate) the line through a and b
+// Time: O(1) − Space: O(1)
+pub fn orientation_2d<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+>(a: &Vector<K>, b: &Vector<K>, c: &Vector<K>) -> Orientation {
+    return sign(simplex_determinant(&[a, b, c]));
+}
+
+// Whether d is on the side of the plane abc from which a, b, c appear
+// counter-clockwise (Positive), on the other side (Negative) or on it (Degenerate)
+// Time: O(1) − Space: O(1)
+pub fn orientation_3d<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+>(a: &Vector<K>, b: &Vector<K>, c: &Vector<K>, d: &Vector<K>) -> Orientation {
+    return sign(simplex_determinant(&[a, b, c, d]));
+}
+
+// Position of d relative to the circle through a, b and c, from the sign of
+//   | ax - dx   ay - dy   (ax - dx)^2 + (ay - dy)^2 |
+//   | bx - dx   by - dy   (bx - dx)^2 + (by - dy)^2 |
+//   | cx - dx   cy - dy   (cx - dx)^2 + (cy - dy)^2 |
+// which is positive for d inside when abc is counter-clockwise
+// Time: O(1) − Space: O(1)
+pub fn in_circle<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+>(a: &Vector<K>, b: &Vector<K>, c: &Vector<K>, d: &Vector<K>) -> Result<Location, &'static str> {
+    let orientation = orientation_2d(a, b, c);
+    if orientation == Orientation::Degenerate {
+        return Err("The three points are collinear");
+    }
+    let d = d.flat();
+    let rows: Vec<Vec<K>> = [a, b, c]
+        .iter()
+        .map(|p| {
+            let p = p.flat();
+            let (x, y) = (p[0] - d[0], p[1] - d[1]);
+            vec![x, y, x * x + y * y]
+        })
+        .collect();
+
+    let mut side = sign(Matrix::from(rows).determinant());
+    if orientation == Orientation::Negative {
+        side = match side {
+            Orientation::Positive => Orientation::Negative,
+            Orientation::Negative => Orientation::Positive,
+            Orientation::Degenerate => Orientation::Degenerate,
+        };
+    }
+    return Ok(match side {
+        Orientation::Positive => Location::Inside,
+        Orientation::Negative => Location::Outside,
+        Orientation::Degenerate => Location::Boundary,
+    });
+}
@@ -0,0 +1,566 @@
+use crate::core::Vector;
+use crate::core::symmetric::symmetric_eigen;
+use crate::iterative::iterative::{IterativeOptions, dot, norm, axpy};
+use crate::iterative::operator::LinearOperator;
+use crate::operations::Operations;
+
+pub struct Eigenpair<K: std::fmt::Display> {
+    pub value: K,
+    // Unit eigenvector, its largest component is positive
+    pub vector: Vector<K>,
+    pub iterations: usize,
+    // Relative residual ||Av - value * v|| / |value|, before the first iteration and after each one
+    pub residuals: Vec<K>,
+    pub converged: bool,
+}
+
+fn square<K, A: LinearOperator<K>>(a: &A) -> Result<usize, &'static str> {
+    let (rows, columns) = a.shape();
+    if rows != columns {
+        return Err("The operator must be square");
+    }
+    return Ok(rows);
+}
+
+// The initial guess of the options, or 1, 2, ..., n
+// which is unlikely to be orthogonal to the wanted eigenvector
+fn start<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+>(n: usize, options: &IterativeOptions<K>) -> Result<Vec<K>, &'static str> {
+    let v = match &options.initial {
+        Some(initial) if initial.shape().0 != n => {
+            return Err("The initial guess does not match the operator");
+        }
+        Some(initial) => initial.flat(),
+        None => {
+            let mut v: Vec<K> = Vec::new();
+            let mut i = K::default();
+            for _ in 0..n {
+                i = i + K::one();
+                v.push(i);
+            }
+            v
+        }
+    };
+    let length = norm(&v);
+    if length == K::default() {
+        return Err("The initial guess must not be zero");
+    }
+    return Ok(v.iter().map(|&v| v / length).collect());
+}
+
+// Rayleigh quotient of a unit vector and the relative residual of the pair
+fn rayleigh<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations,
+    A: LinearOperator<K>,
+>(a: &A, v: &[K]) -> (K, K) {
+    let w = a.apply(v);
+    let value = dot(v, &w);
+    let residual = norm(&axpy(&w, -value, v));
+    if value == K::default() {
+        return (value, residual);
+    }
+    return (value, residual / value.abs());
+}
+
+fn finish<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+>(value: K, mut v: Vec<K>, residuals: Vec<K>, converged: bool) -> Eigenpair<K> {
+    let mut largest = 0;
+    for i in 0..v.len() {
+        if v[i].abs() > v[largest].abs() {
+            largest = i;
+        }
+    }
+    if !v.is_empty() && v[largest] < K::default() {
+        v = v.iter().map(|&v| -v).collect();
+    }
+    return Eigenpair {
+        value,
+        vector: Vector::from(v),
+        iterations: residuals.len().saturating_sub(1),
+        residuals,
+        converged,
+    };
+}
+
+// Dominant eigenpair, the one of largest |value|
+// Time: O(k * nnz) − Space: O(n)
+// where k is the number of iterations
+pub fn power_iteration<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations,
+    A: LinearOperator<K>,
+>(a: &A, options: &IterativeOptions<K>) -> Result<Eigenpair<K>, &'static str> {
+    let n = square(a)?;
+    let mut v = start(n, options)?;
+    let (mut value, residual) = rayleigh(a, &v);
+    let mut residuals = vec![residual];
+
+    while residuals[residuals.len() - 1] > options.tolerance
+        && residuals.len() <= options.max_iterations
+    {
+        let w = a.apply(&v);
+        let length = norm(&w);
+        if length == K::default() {
+            return Err("The operator maps the iterate to zero");
+        }
+        v = w.iter().map(|&w| w / length).collect();
+        let (next, residual) = rayleigh(a, &v);
+        value = next;
+        residuals.push(residual);
+    }
+
+    let converged = residuals[residuals.len() - 1] <= options.tolerance;
+    return Ok(finish(value, v, residuals, converged));
+}
+
+// LU factorization of A - shift * I with partial pivoting, dense:
+// inverse iteration needs exact solves of a nearly singular system
+fn shifted_lu<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+>(rows: &[Vec<(usize, K)>], shift: K) -> Result<(Vec<Vec<K>>, Vec<usize>), &'static str> {
+    let n = rows.len();
+    let mut lu: Vec<Vec<K>> = vec![vec![K::default(); n]; n];
+    for (i, row) in rows.iter().enumerate() {
+        for &(j, v) in row {
+            lu[i][j] = v;
+        }
+        lu[i][i] = lu[i][i] - shift;
+    }
+    let mut permutation: Vec<usize> = (0..n).collect();
+
+    for k in 0..n {
+        let mut pivot = k;
+        for i in k + 1..n {
+            if lu[i][k].abs() > lu[pivot][k].abs() {
+                pivot = i;
+            }
+        }
+        if lu[pivot][k] == K::default() {
+            return Err("The shift is an eigenvalue, the shifted matrix is singular");
+        }
+        lu.swap(k, pivot);
+        permutation.swap(k, pivot);
+        for i in k + 1..n {
+            let factor = lu[i][k] / lu[k][k];
+            lu[i][k] = factor;
+            for j in k + 1..n {
+                lu[i][j] = lu[i][j] - factor * lu[k][j];
+            }
+        }
+    }
+    return Ok((lu, permutation));
+}
+
+fn lu_solve<
+    K: std::ops::Sub<Output = K> + std::ops::Mul<Output = K> + std::ops::Div<Output = K> + Copy
+>(lu: &[Vec<K>], permutation: &[usize], b: &[K]) -> Vec<K> {
+    let n = lu.len();
+    let mut x: Vec<K> = permutation.iter().map(|&p| b[p]).collect();
+    for i in 0..n {
+        for j in 0..i {
+            x[i] = x[i] - lu[i][j] * x[j];
+        }
+    }
+    for i in (0..n).rev() {
+        for j in i + 1..n {
+            x[i] = x[i] - lu[i][j] * x[j];
+        }
+        x[i] = x[i] / lu[i][i];
+    }
+    return x;
+}
+
+// Iterates v <- (A - shift * I)^-1 v, moving the shift to the
+// Rayleigh quotient after the first iteration when asked to
+fn shifted_iteration<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations,
+    A: LinearOperator<K>,
+>(
+    a: &A,
+    shift: K,
+    update_shift: bool,
+    options: &IterativeOptions<K>,
+) -> Result<Eigenpair<K>, &'static str> {
+    let n = square(a)?;
+    let rows = a.rows();
+    let mut v = start(n, options)?;
+    let (mut lu, mut permutation) = shifted_lu(&rows, shift)?;
+    let (mut value, residual) = rayleigh(a, &v);
+    let mut residuals = vec![residual];
+
+    while residuals[residuals.len() - 1] > options.tolerance
+        && residuals.len() <= options.max_iterations
+    {
+        if update_shift && residuals.len() > 1 {
+            (lu, permutation) = shifted_lu(&rows, value)?;
+        }
+        let y = lu_solve(&lu, &permutation, &v);
+        let length = norm(&y);
+        v = y.iter().map(|&y| y / length).collect();
+        let (next, residual) = rayleigh(a, &v);
+        value = next;
+        residuals.push(residual);
+    }
+
+    let converged = residuals[residuals.len() - 1] <= options.tolerance;
+    return Ok(finish(value, v, residuals, converged));
+}
+
+// Eigenpair whose value is closest to the shift
+// Time: O(n^3 + k * n^2) − Space: O(n^2)
+pub fn inverse_iteration<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations,
+    A: LinearOperator<K>,
+>(a: &A, shift: K, options: &IterativeOptions<K>) -> Result<Eigenpair<K>, &'static str> {
+    return shifted_iteration(a, shift, false, options);
+}
+
+// Inverse iteration whose shift follows the Rayleigh quotient,
+// cubically convergent for symmetric operators.
+// The eigenpair found is usually, but not always, the one closest to the initial shift.
+// Time: O(k * n^3) − Space: O(n^2)
+pub fn rayleigh_quotient_iteration<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations,
+    A: LinearOperator<K>,
+>(a: &A, shift: K, options: &IterativeOptions<K>) -> Result<Eigenpair<K>, &'static str> {
+    return shifted_iteration(a, shift, true, options);
+}
+
+// Orthonormal Krylov basis of span(v, Av, ..., A^(steps - 1) v),
+// with modified Gram-Schmidt run twice to keep it orthogonal,
+// and the (steps + 1) x steps Hessenberg matrix H such that A Q = Q H.
+// Stops early on an invariant subspace, once orthogonalization leaves
+// no more than rounding residue of Aq (exactly zero for exact scalars).
+fn krylov<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations,
+    A: LinearOperator<K>,
+>(a: &A, v: Vec<K>, steps: usize) -> (Vec<Vec<K>>, Vec<Vec<K>>) {
+    let mut basis: Vec<Vec<K>> = vec![v];
+    let mut h: Vec<Vec<K>> = vec![vec![K::default(); steps]; steps + 1];
+
+    for j in 0..steps {
+        let mut w = a.apply(&basis[j]);
+        let mut threshold = K::default();
+        let scale = K::epsilon() * norm(&w);
+        for _ in 0..w.len() {
+            threshold = threshold + scale;
+        }
+        for _ in 0..2 {
+            for i in 0..=j {
+                let hij = dot(&w, &basis[i]);
+                w = axpy(&w, -hij, &basis[i]);
+                h[i][j] = h[i][j] + hij;
+            }
+        }
+        let length = norm(&w);
+        h[j + 1][j] = length;
+        if length <= threshold {
+            h.truncate(j + 1);
+            for row in h.iter_mut() {
+                row.truncate(j + 1);
+            }
+            return (basis, h);
+        }
+        basis.push(w.iter().map(|&w| w / length).collect());
+    }
+    basis.truncate(steps);
+    h.truncate(steps);
+    return (basis, h);
+}
+
+// The `count` eigenpairs of largest |value| of a symmetric operator,
+// from a Krylov subspace of dimension `steps` (Lanczos with full reorthogonalization).
+// Each pair records the residual of the Ritz pair, `iterations` is the subspace dimension.
+// Fails when the subspace ends up smaller than `count`, because steps > n or on early invariance.
+// Time: O(steps * nnz + steps^2 * n) − Space: O(steps * n)
+pub fn lanczos<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations,
+    A: LinearOperator<K>,
+>(
+    a: &A,
+    count: usize,
+    steps: usize,
+    options: &IterativeOptions<K>,
+) -> Result<Vec<Eigenpair<K>>, &'static str> {
+    let n = square(a)?;
+    if count == 0 || count > steps {
+        return Err("The number of eigenvalues must be in [1, steps]");
+    }
+    let (basis, h) = krylov(a, start(n, options)?, steps.min(n));
+    if basis.len() < count {
+        return Err("The Krylov subspace is smaller than the number of eigenvalues");
+    }
+
+    // The Hessenberg matrix of a symmetric operator is tridiagonal
+    let m = basis.len();
+    let mut t: Vec<Vec<K>> = vec![vec![K::default(); m]; m];
+    for i in 0..m {
+        t[i][i] = h[i][i];
+        if i + 1 < m {
+            t[i][i + 1] = h[i + 1][i];
+            t[i + 1][i] = h[i + 1][i];
+        }
+    }
+    let (values, vectors) = symmetric_eigen(t, options.tolerance);
+
+    let mut order: Vec<usize> = (0..m).collect();
+    order.sort_by(|&i, &j| values[j].abs().partial_cmp(&values[i].abs()).unwrap_or(std::cmp::Ordering::Equal));
+
+    let mut pairs: Vec<Eigenpair<K>> = Vec::new();
+    for &i in order.iter().take(count) {
+        let mut v: Vec<K> = vec![K::default(); n];
+        for j in 0..m {
+            v = axpy(&v, vectors[j][i], &basis[j]);
+        }
+        let (_, residual) = rayleigh(a, &v);
+        let converged = residual <= options.tolerance;
+        let mut pair = finish(values[i], v, vec![residual], converged);
+        pair.iterations = m;
+        pairs.push(pair);
+    }
+    return Ok(pairs);
+}
+
+// Eigenvalues (real, imaginary) of an upper Hessenberg matrix,
+// by the shifted QR algorithm with deflation
+fn hessenberg_eigenvalues<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+>(mut h: Vec<Vec<K>>, tolerance: K) -> Result<Vec<(K, K)>, &'static str> {
+    let zero = K::default();
+    let two = K::one() + K::one();
+    let mut values: Vec<(K, K)> = Vec::new();
+    let mut end = h.len();
+    let mut stalled = 0;
+
+    while end > 0 {
+        // Start of the unreduced block ending at end - 1
+        let mut start = end - 1;
+        while start > 0 {
+            let scale = h[start][start].abs() + h[start - 1][start - 1].abs();
+            if h[start][start - 1].abs() <= tolerance * scale {
+                h[start][start - 1] = zero;
+                break;
+            }
+            start -= 1;
+        }
+
+        if start == end - 1 {
+            values.push((h[start][start], zero));
+            end -= 1;
+            stalled = 0;
+            continue;
+        }
+        let (a, b) = (h[end - 2][end - 2], h[end - 2][end - 1]);
+        let (c, d) = (h[end - 1][end - 2], h[end - 1][end - 1]);
+        let half = (a + d) / two;
+        let discriminant = (a - d) * (a - d) / (two * two) + b * c;
+        if start == end - 2 {
+            if discriminant < zero {
+                let imaginary = (-discriminant).sqrt();
+                values.push((half, imaginary));
+                values.push((half, -imaginary));
+            } else {
+                values.push((half + discriminant.sqrt(), zero));
+                values.push((half - discriminant.sqrt(), zero));
+            }
+            end -= 2;
+            stalled = 0;
+            continue;
+        }
+
+        stalled += 1;
+        if stalled > 100 * h.len() {
+            return Err("The QR algorithm did not converge");
+        }
+        // Wilkinson shift when the trailing 2x2 block has real eigenvalues,
+        // an exceptional shift every 10 iterations to break cycles
+        let mut shift = d;
+        if discriminant >= zero {
+            let root = discriminant.sqrt();
+            let (high, low) = (half + root, half - root);
+            shift = if (high - d).abs() < (low - d).abs() { high } else { low };
+        }
+        if stalled % 10 == 0 {
+            shift = d + h[end - 1][end - 2].abs();
+        }
+
+        // One QR step on the block, H - shift * I = QR then H <- RQ + shift * I
+        for i in start..end {
+            h[i][i] = h[i][i] - shift;
+        }
+        let mut rotations: Vec<(K, K)> = Vec::new();
+        for i in start..end - 1 {
+            let (x, y) = (h[i][i], h[i + 1][i]);
+            let radius = (x * x + y * y).sqrt();
+            let (cos, sin) = if radius == zero { (K::one(), zero) } else { (x / radius, y / radius) };
+            for j in i..end {
+                let (x, y) = (h[i][j], h[i + 1][j]);
+                h[i][j] = cos * x + sin * y;
+                h[i + 1][j] = -sin * x + cos * y;
+            }
+            rotations.push((cos, sin));
+        }
+        for (k, &(cos, sin)) in rotations.iter().enumerate() {
+            let i = start + k;
+            for r in start..(i + 2).min(end) {
+                let (x, y) = (h[r][i], h[r][i + 1]);
+                h[r][i] = cos * x + sin * y;
+                h[r][i + 1] = -sin * x + cos * y;
+            }
+        }
+        for i in start..end {
+            h[i][i] = h[i][i] + shift;
+        }
+    }
+
+    return Ok(values);
+}
+
+// The `count` eigenvalues (real, imaginary) of largest modulus of any square operator,
+// approximated by the Ritz values of a Krylov subspace of dimension `steps` (Arnoldi)
+// Time: O(steps * nnz + steps^2 * n + steps^3) − Space: O(steps * n)
+pub fn arnoldi<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations,
+    A: LinearOperator<K>,
+>(
+    a: &A,
+    count: usize,
+    steps: usize,
+    options: &IterativeOptions<K>,
+) -> Result<Vec<(K, K)>, &'static str> {
+    let n = square(a)?;
+    if count == 0 || count > steps {
+        return Err("The number of eigenvalues must be in [1, steps]");
+    }
+    let (basis, h) = krylov(a, start(n, options)?, steps.min(n));
+    if basis.len() < count {
+        return Err("The Krylov subspace is smaller than the number of eigenvalues");
+    }
+
+    let mut values = hessenberg_eigenvalues(h, options.tolerance)?;
+    let modulus = |&(re, im): &(K, K)| re * re + im * im;
+    values.sort_by(|u, v| modulus(v).partial_cmp(&modulus(u)).unwrap_or(std::cmp::Ordering::Equal));
+    values.truncate(count);
+    return Ok(values);
+}
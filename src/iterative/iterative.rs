@@ -0,0 +1,475 @@
+use crate::core::Vector;
+use crate::iterative::operator::LinearOperator;
+use crate::iterative::preconditioner::{Preconditioner, Factorization, diagonal};
+use crate::operations::Operations;
+
+pub struct IterativeOptions<K: std::fmt::Display> {
+    // Stop once ||b - Ax|| <= tolerance * ||b||
+    pub tolerance: K,
+    pub max_iterations: usize,
+    // Starting guess, zero when None
+    pub initial: Option<Vector<K>>,
+    // Only used by the Krylov methods (CG, BiCGSTAB and GMRES)
+    pub preconditioner: Preconditioner,
+}
+
+impl<K: std::fmt::Display> IterativeOptions<K> {
+    pub fn new(tolerance: K, max_iterations: usize) -> IterativeOptions<K> {
+        return IterativeOptions {
+            tolerance,
+            max_iterations,
+            initial: None,
+            preconditioner: Preconditioner::None,
+        };
+    }
+}
+
+pub struct Solution<K: std::fmt::Display> {
+    pub x: Vector<K>,
+    pub iterations: usize,
+    // Relative residual ||b - Ax|| / ||b||, before the first iteration and after each one
+    pub residuals: Vec<K>,
+    pub converged: bool,
+}
+
+pub(crate) fn dot<
+    K: std::ops::Add<Output = K> + std::ops::Mul<Output = K> + Default + Copy
+>(u: &[K], v: &[K]) -> K {
+    let mut sum = K::default();
+    for i in 0..u.len() {
+        sum = sum + u[i] * v[i];
+    }
+    return sum;
+}
+
+pub(crate) fn norm<
+    K: std::ops::Add<Output = K> + std::ops::Mul<Output = K> + Default + Copy + Operations
+>(u: &[K]) -> K {
+    return dot(u, u).sqrt();
+}
+
+// u + a * v
+pub(crate) fn axpy<
+    K: std::ops::Add<Output = K> + std::ops::Mul<Output = K> + Copy
+>(u: &[K], a: K, v: &[K]) -> Vec<K> {
+    return u.iter().zip(v).map(|(&u, &v)| u + a * v).collect();
+}
+
+// Shared state of every solver: the iterate, the residual history
+// and the stopping criterion
+struct Run<'a, K: std::fmt::Display, A: LinearOperator<K>> {
+    a: &'a A,
+    b: Vec<K>,
+    b_norm: K,
+    x: Vec<K>,
+    options: &'a IterativeOptions<K>,
+    residuals: Vec<K>,
+}
+
+impl<
+        'a,
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations,
+        A: LinearOperator<K>,
+    > Run<'a, K, A>
+{
+    fn new(a: &'a A, b: &Vector<K>, options: &'a IterativeOptions<K>) -> Result<Self, &'static str> {
+        let (rows, columns) = a.shape();
+        if rows != columns {
+            return Err("The operator must be square");
+        }
+        if b.shape().0 != rows {
+            return Err("The right-hand side does not match the operator");
+        }
+        let x = match &options.initial {
+            Some(initial) if initial.shape().0 != rows => {
+                return Err("The initial guess does not match the operator");
+            }
+            Some(initial) => initial.flat(),
+            None => vec![K::default(); rows],
+        };
+        let b = b.flat();
+        let mut b_norm = norm(&b);
+        // Ax = 0 is solved by x = 0, measure the absolute residual
+        if b_norm == K::default() {
+            b_norm = K::one();
+        }
+        return Ok(Run {
+            a,
+            b,
+            b_norm,
+            x,
+            options,
+            residuals: Vec::new(),
+        });
+    }
+
+    fn residual(&self) -> Vec<K> {
+        let ax = self.a.apply(&self.x);
+        return self.b.iter().zip(ax).map(|(&b, ax)| b - ax).collect();
+    }
+
+    // Records the residual and tells whether the tolerance is met
+    fn record(&mut self, residual_norm: K) -> bool {
+        let relative = residual_norm / self.b_norm;
+        self.residuals.push(relative);
+        return relative <= self.options.tolerance;
+    }
+
+    fn iterations(&self) -> usize {
+        return self.residuals.len().saturating_sub(1);
+    }
+
+    fn finish(self, converged: bool) -> Solution<K> {
+        return Solution {
+            iterations: self.iterations(),
+            x: Vector::from(self.x),
+            residuals: self.residuals,
+            converged,
+        };
+    }
+}
+
+// Time: O(k * nnz) − Space: O(nnz)
+// where k is the number of iterations
+pub fn jacobi<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations,
+    A: LinearOperator<K>,
+>(a: &A, b: &Vector<K>, options: &IterativeOptions<K>) -> Result<Solution<K>, &'static str> {
+    let mut run = Run::new(a, b, options)?;
+    let rows = a.rows();
+    let d = diagonal(&rows)?;
+
+    let norm_r = norm(&run.residual());
+    if run.record(norm_r) {
+        return Ok(run.finish(true));
+    }
+    while run.iterations() < options.max_iterations {
+        let mut next: Vec<K> = Vec::new();
+        for i in 0..rows.len() {
+            let mut sum = run.b[i];
+            for &(j, v) in &rows[i] {
+                if j != i {
+                    sum = sum - v * run.x[j];
+                }
+            }
+            next.push(sum / d[i]);
+        }
+        run.x = next;
+        let norm_r = norm(&run.residual());
+        if run.record(norm_r) {
+            return Ok(run.finish(true));
+        }
+    }
+    return Ok(run.finish(false));
+}
+
+// Time: O(k * nnz) − Space: O(nnz)
+pub fn gauss_seidel<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations,
+    A: LinearOperator<K>,
+>(a: &A, b: &Vector<K>, options: &IterativeOptions<K>) -> Result<Solution<K>, &'static str> {
+    return sor(a, b, K::one(), options);
+}
+
+// Successive over-relaxation, Gauss-Seidel when omega is 1
+// Time: O(k * nnz) − Space: O(nnz)
+pub fn sor<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations,
+    A: LinearOperator<K>,
+>(
+    a: &A,
+    b: &Vector<K>,
+    omega: K,
+    options: &IterativeOptions<K>,
+) -> Result<Solution<K>, &'static str> {
+    if omega <= K::default() || omega >= K::one() + K::one() {
+        return Err("The relaxation factor must be in (0, 2)");
+    }
+    let mut run = Run::new(a, b, options)?;
+    let rows = a.rows();
+    let d = diagonal(&rows)?;
+
+    let norm_r = norm(&run.residual());
+    if run.record(norm_r) {
+        return Ok(run.finish(true));
+    }
+    while run.iterations() < options.max_iterations {
+        for i in 0..rows.len() {
+            let mut sum = run.b[i];
+            for &(j, v) in &rows[i] {
+                if j != i {
+                    sum = sum - v * run.x[j];
+                }
+            }
+            run.x[i] = (K::one() - omega) * run.x[i] + omega * sum / d[i];
+        }
+        let norm_r = norm(&run.residual());
+        if run.record(norm_r) {
+            return Ok(run.finish(true));
+        }
+    }
+    return Ok(run.finish(false));
+}
+
+// Preconditioned conjugate gradient, for symmetric positive definite operators
+// Time: O(k * nnz) − Space: O(n)
+pub fn conjugate_gradient<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations,
+    A: LinearOperator<K>,
+>(a: &A, b: &Vector<K>, options: &IterativeOptions<K>) -> Result<Solution<K>, &'static str> {
+    let mut run = Run::new(a, b, options)?;
+    let m = Factorization::new(a, options.preconditioner)?;
+
+    let mut r = run.residual();
+    if run.record(norm(&r)) {
+        return Ok(run.finish(true));
+    }
+    let mut z = m.solve(&r);
+    let mut p = z.clone();
+    let mut rz = dot(&r, &z);
+
+    while run.iterations() < options.max_iterations {
+        let ap = a.apply(&p);
+        let pap = dot(&p, &ap);
+        if pap == K::default() {
+            break;
+        }
+        let alpha = rz / pap;
+        run.x = axpy(&run.x, alpha, &p);
+        r = axpy(&r, -alpha, &ap);
+        if run.record(norm(&r)) {
+            return Ok(run.finish(true));
+        }
+        z = m.solve(&r);
+        let rz_next = dot(&r, &z);
+        let beta = rz_next / rz;
+        rz = rz_next;
+        p = axpy(&z, beta, &p);
+    }
+    return Ok(run.finish(false));
+}
+
+// Right-preconditioned BiCGSTAB, for general square operators
+// Time: O(k * nnz) − Space: O(n)
+pub fn bicgstab<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations,
+    A: LinearOperator<K>,
+>(a: &A, b: &Vector<K>, options: &IterativeOptions<K>) -> Result<Solution<K>, &'static str> {
+    let mut run = Run::new(a, b, options)?;
+    let m = Factorization::new(a, options.preconditioner)?;
+    let n = run.x.len();
+
+    let mut r = run.residual();
+    if run.record(norm(&r)) {
+        return Ok(run.finish(true));
+    }
+    let shadow = r.clone();
+    let (mut rho, mut alpha, mut omega) = (K::one(), K::one(), K::one());
+    let mut v: Vec<K> = vec![K::default(); n];
+    let mut p: Vec<K> = vec![K::default(); n];
+
+    while run.iterations() < options.max_iterations {
+        let rho_next = dot(&shadow, &r);
+        if rho_next == K::default() || omega == K::default() {
+            break;
+        }
+        let beta = (rho_next / rho) * (alpha / omega);
+        p = axpy(&r, beta, &axpy(&p, -omega, &v));
+        let p_hat = m.solve(&p);
+        v = a.apply(&p_hat);
+        let shadow_v = dot(&shadow, &v);
+        if shadow_v == K::default() {
+            break;
+        }
+        alpha = rho_next / shadow_v;
+        let s = axpy(&r, -alpha, &v);
+
+        if norm(&s) / run.b_norm <= options.tolerance {
+            run.x = axpy(&run.x, alpha, &p_hat);
+            run.record(norm(&s));
+            return Ok(run.finish(true));
+        }
+
+        let s_hat = m.solve(&s);
+        let t = a.apply(&s_hat);
+        let tt = dot(&t, &t);
+        if tt == K::default() {
+            break;
+        }
+        omega = dot(&t, &s) / tt;
+        run.x = axpy(&axpy(&run.x, alpha, &p_hat), omega, &s_hat);
+        r = axpy(&s, -omega, &t);
+        rho = rho_next;
+        if run.record(norm(&r)) {
+            return Ok(run.finish(true));
+        }
+    }
+    return Ok(run.finish(false));
+}
+
+// Right-preconditioned GMRES, restarted every `restart` iterations
+// Time: O(k * (nnz + n * restart)) − Space: O(n * restart)
+pub fn gmres<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations,
+    A: LinearOperator<K>,
+>(
+    a: &A,
+    b: &Vector<K>,
+    restart: usize,
+    options: &IterativeOptions<K>,
+) -> Result<Solution<K>, &'static str> {
+    if restart == 0 {
+        return Err("The restart length must be positive");
+    }
+    let mut run = Run::new(a, b, options)?;
+    let m = Factorization::new(a, options.preconditioner)?;
+    let n = run.x.len();
+
+    let r = run.residual();
+    if run.record(norm(&r)) {
+        return Ok(run.finish(true));
+    }
+
+    while run.iterations() < options.max_iterations {
+        let r = run.residual();
+        let beta = norm(&r);
+        if beta == K::default() {
+            return Ok(run.finish(true));
+        }
+
+        // Arnoldi basis, Hessenberg matrix (one column per step)
+        // and Givens rotations reducing it to upper triangular
+        let mut basis: Vec<Vec<K>> = vec![r.iter().map(|&v| v / beta).collect()];
+        let mut h: Vec<Vec<K>> = Vec::new();
+        let mut rotations: Vec<(K, K)> = Vec::new();
+        let mut g: Vec<K> = vec![beta];
+        let mut converged = false;
+
+        for j in 0..restart {
+            if run.iterations() >= options.max_iterations {
+                break;
+            }
+            let mut w = a.apply(&m.solve(&basis[j]));
+            let mut column: Vec<K> = Vec::new();
+            for i in 0..=j {
+                let hij = dot(&w, &basis[i]);
+                w = axpy(&w, -hij, &basis[i]);
+                column.push(hij);
+            }
+            let next = norm(&w);
+            column.push(next);
+
+            for (i, &(c, s)) in rotations.iter().enumerate() {
+                let (x, y) = (column[i], column[i + 1]);
+                column[i] = c * x + s * y;
+                column[i + 1] = -s * x + c * y;
+            }
+            let (x, y) = (column[j], column[j + 1]);
+            let radius = (x * x + y * y).sqrt();
+            let (c, s) = (x / radius, y / radius);
+            column[j] = radius;
+            column[j + 1] = K::default();
+            rotations.push((c, s));
+            g.push(-s * g[j]);
+            g[j] = c * g[j];
+            h.push(column);
+
+            converged = run.record(g[j + 1].abs());
+            if converged || next == K::default() {
+                break;
+            }
+            basis.push(w.iter().map(|&v| v / next).collect());
+        }
+
+        // Back substitution of the triangular system H y = g
+        let k = h.len();
+        let mut y: Vec<K> = vec![K::default(); k];
+        for i in (0..k).rev() {
+            let mut sum = g[i];
+            for l in i + 1..k {
+                sum = sum - h[l][i] * y[l];
+            }
+            y[i] = sum / h[i][i];
+        }
+        let mut update: Vec<K> = vec![K::default(); n];
+        for i in 0..k {
+            update = axpy(&update, y[i], &basis[i]);
+        }
+        run.x = axpy(&run.x, K::one(), &m.solve(&update));
+
+        if converged {
+            return Ok(run.finish(true));
+        }
+    }
+    return Ok(run.finish(false));
+}
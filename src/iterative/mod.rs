@@ -0,0 +1,10 @@
+pub mod operator;
+pub mod preconditioner;
+#[allow(clippy::module_inception)]
+pub mod iterative;
+pub mod eigen;
+
+pub use operator::*;
+pub use preconditioner::*;
+pub use iterative::*;
+pub use eigen::*;
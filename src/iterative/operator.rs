@@ -0,0 +1,96 @@
+use crate::core::{Matrix, SparseMatrix, SparseFormat};
+use crate::operations::Operations;
+
+// Anything that can be multiplied by a vector.
+// Stationary methods and ILU(0) also need the stored entries of each row.
+pub trait LinearOperator<K> {
+    fn shape(&self) -> (usize, usize);
+
+    // self * x
+    fn apply(&self, x: &[K]) -> Vec<K>;
+
+    // Non-zero entries (column, value) of every row, sorted by column
+    fn rows(&self) -> Vec<Vec<(usize, K)>>;
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > LinearOperator<K> for Matrix<K>
+{
+    fn shape(&self) -> (usize, usize) {
+        return Matrix::shape(self);
+    }
+
+    // Time: O(mn) − Space: O(m)
+    fn apply(&self, x: &[K]) -> Vec<K> {
+        let (rows, columns) = Matrix::shape(self);
+        let mut result: Vec<K> = vec![K::default(); rows];
+        for r in 0..rows {
+            for c in 0..columns {
+                result[r] = result[r] + self.get(r, c) * x[c];
+            }
+        }
+        return result;
+    }
+
+    // Time: O(mn) − Space: O(nnz)
+    fn rows(&self) -> Vec<Vec<(usize, K)>> {
+        let (rows, columns) = Matrix::shape(self);
+        return (0..rows)
+            .map(|r| {
+                (0..columns)
+                    .map(|c| (c, self.get(r, c)))
+                    .filter(|(_, v)| *v != K::default())
+                    .collect()
+            })
+            .collect();
+    }
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > LinearOperator<K> for SparseMatrix<K>
+{
+    fn shape(&self) -> (usize, usize) {
+        return SparseMatrix::shape(self);
+    }
+
+    // Time: O(nnz + m) − Space: O(m)
+    fn apply(&self, x: &[K]) -> Vec<K> {
+        let mut result: Vec<K> = vec![K::default(); SparseMatrix::shape(self).0];
+        self.for_each(|r, c, v| result[r] = result[r] + v * x[c]);
+        return result;
+    }
+
+    // Time: O(nnz) for CSR, O(nnz log nnz) for CSC − Space: O(nnz)
+    fn rows(&self) -> Vec<Vec<(usize, K)>> {
+        let csr = if self.format() == SparseFormat::Csr {
+            self.clone()
+        } else {
+            self.to_csr()
+        };
+        let mut rows: Vec<Vec<(usize, K)>> = vec![Vec::new(); SparseMatrix::shape(self).0];
+        csr.for_each(|r, c, v| rows[r].push((c, v)));
+        return rows;
+    }
+}
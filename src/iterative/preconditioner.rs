@@ -0,0 +1,156 @@
+use crate::iterative::operator::LinearOperator;
+use crate::operations::Operations;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Preconditioner {
+    None,
+    // M = diag(A)
+    Jacobi,
+    // M = LU, with the sparsity pattern of A
+    Ilu0,
+}
+
+// A preconditioner, ready to solve M z = r
+pub enum Factorization<K> {
+    Identity,
+    Diagonal(Vec<K>),
+    // Unit lower L and upper U stored together, row by row
+    Ilu(Vec<Vec<(usize, K)>>),
+}
+
+fn find<K: Copy>(row: &[(usize, K)], column: usize) -> Option<usize> {
+    return row.binary_search_by_key(&column, |&(c, _)| c).ok();
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+    > Factorization<K>
+{
+    // Time: O(n) for Jacobi, O(sum of the squared row lengths) for ILU(0)
+    pub fn new<A: LinearOperator<K>>(
+        a: &A,
+        preconditioner: Preconditioner,
+    ) -> Result<Factorization<K>, &'static str> {
+        return match preconditioner {
+            Preconditioner::None => Ok(Factorization::Identity),
+            Preconditioner::Jacobi => Ok(Factorization::Diagonal(diagonal(&a.rows())?)),
+            Preconditioner::Ilu0 => Ok(Factorization::Ilu(ilu0(a.rows())?)),
+        };
+    }
+
+    // z such that M z = r
+    // Time: O(n) for Jacobi, O(nnz) for ILU(0)
+    pub fn solve(&self, r: &[K]) -> Vec<K> {
+        return match self {
+            Factorization::Identity => r.to_vec(),
+            Factorization::Diagonal(d) => r.iter().zip(d).map(|(&r, &d)| r / d).collect(),
+            Factorization::Ilu(lu) => {
+                let n = r.len();
+                // L y = r, L has a unit diagonal
+                let mut y: Vec<K> = r.to_vec();
+                for i in 0..n {
+                    for &(j, v) in &lu[i] {
+                        if j >= i {
+                            break;
+                        }
+                        y[i] = y[i] - v * y[j];
+                    }
+                }
+                // U z = y
+                for i in (0..n).rev() {
+                    let mut pivot = K::one();
+                    for &(j, v) in &lu[i] {
+                        if j == i {
+                            pivot = v;
+                        } else if j > i {
+                            y[i] = y[i] - v * y[j];
+                        }
+                    }
+                    y[i] = y[i] / pivot;
+                }
+                y
+            }
+        };
+    }
+}
+
+// Diagonal of A, which must not hold any zero
+pub fn diagonal<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+>(rows: &[Vec<(usize, K)>]) -> Result<Vec<K>, &'static str> {
+    let mut d: Vec<K> = Vec::new();
+    for (i, row) in rows.iter().enumerate() {
+        match find(row, i) {
+            Some(at) if row[at].1 != K::default() => d.push(row[at].1),
+            _ => return Err("The diagonal holds a zero"),
+        }
+    }
+    return Ok(d);
+}
+
+// Incomplete LU factorization without fill-in (IKJ variant)
+fn ilu0<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+>(mut lu: Vec<Vec<(usize, K)>>) -> Result<Vec<Vec<(usize, K)>>, &'static str> {
+    diagonal(&lu)?;
+
+    for i in 1..lu.len() {
+        for at in 0..lu[i].len() {
+            let k = lu[i][at].0;
+            if k >= i {
+                break;
+            }
+            let pivot = lu[k][find(&lu[k], k).unwrap()].1;
+            if pivot == K::default() {
+                return Err("A zero pivot appeared during ILU(0)");
+            }
+            let factor = lu[i][at].1 / pivot;
+            lu[i][at].1 = factor;
+
+            // Only update the entries that already exist in row i
+            for next in at + 1..lu[i].len() {
+                let j = lu[i][next].0;
+                if let Some(kj) = find(&lu[k], j) {
+                    let update = factor * lu[k][kj].1;
+                    lu[i][next].1 = lu[i][next].1 - update;
+                }
+            }
+        }
+    }
+
+    // The last pivots are never divided by above, so U is checked as a whole
+    if diagonal(&lu).is_err() {
+        return Err("A zero pivot appeared during ILU(0)");
+    }
+    return Ok(lu);
+}
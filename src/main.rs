@@ -1,6 +1,12 @@
+#![allow(clippy::needless_return, clippy::needless_range_loop)]
+
 mod core;
 mod test;
 mod operations;
+mod transform;
+mod render;
+mod iterative;
+mod geometry;
 use test::*;
 
 use colored::Colorize;
@@ -23,6 +29,31 @@ fn main() {
         ("13 - Rank", test_13),
         ("14 - Projection matrix", test_14),
         ("15 - Complex numbers", test_15),
+        ("16 - Row reduction trace", test_16),
+        ("17 - Echelon forms", test_17),
+        ("18 - Fundamental subspaces", test_18),
+        ("19 - Span and bases", test_19),
+        ("20 - Vector geometry", test_20),
+        ("21 - Fixed-size matrices", test_21),
+        ("22 - Transforms", test_22),
+        ("23 - Projection variants", test_23),
+        ("24 - Quaternions", test_24),
+        ("25 - Software renderer", test_25),
+        ("26 - Sparse matrices", test_26),
+        ("27 - Iterative solvers", test_27),
+        ("28 - Eigenvalue iterations", test_28),
+        ("29 - Rational numbers", test_29),
+        ("30 - Big integers", test_30),
+        ("31 - Fraction-free elimination", test_31),
+        ("32 - Finite fields", test_32),
+        ("33 - Integer normal forms", test_33),
+        ("34 - Polynomials", test_34),
+        ("35 - Matrix functions", test_35),
+        ("36 - Sylvester and Lyapunov equations", test_36),
+        ("37 - Cofactors and adjugate", test_37),
+        ("38 - Cramer's rule and geometric predicates", test_38),
+        ("39 - Structured matrices", test_39),
+        ("40 - Symmetric matrices", test_40),
     ];
 
     for (title, test) in tests {
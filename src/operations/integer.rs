@@ -0,0 +1,52 @@
+use std::fmt::Display;
+use std::ops::{Add, Sub, Mul, Div, Rem, Neg};
+
+use crate::operations::Operations;
+
+// Signed integers with an exact Euclidean division,
+// used as the backing of core::Rational
+pub trait Integer:
+    Clone
+    + PartialEq
+    + PartialOrd
+    + Default
+    + Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+    + Operations
+{
+    fn to_f64(self) -> f64;
+
+    // Non-negative greatest common divisor, gcd(0, 0) = 0
+    fn gcd(a: Self, b: Self) -> Self {
+        let (mut a, mut b) = (a.abs(), b.abs());
+        while b != Self::default() {
+            let r = a % b.clone();
+            a = b;
+            b = r;
+        }
+        return a;
+    }
+}
+
+impl Integer for i32 {
+    fn to_f64(self) -> f64 {
+        return self as f64;
+    }
+}
+
+impl Integer for i64 {
+    fn to_f64(self) -> f64 {
+        return self as f64;
+    }
+}
+
+impl Integer for i128 {
+    fn to_f64(self) -> f64 {
+        return self as f64;
+    }
+}
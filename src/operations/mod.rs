@@ -1,3 +1,6 @@
+#[allow(clippy::module_inception)]
 pub mod operations;
+pub mod integer;
 
 pub use operations::*;
+pub use integer::*;
@@ -1,7 +1,34 @@
+use std::ops::{Add, Sub, Mul, Div};
 
 pub trait Operations {
 	fn abs(&self) -> Self;
     fn sqrt(&self) -> Self;
+    fn one() -> Self;
+    // Elimination helper: exact for integers, within an absolute tolerance
+    // for floating points so that rounding residue is not taken for a pivot.
+    // Not meant for exactness or structure decisions, which compare with
+    // K::default() since a legitimate float may well be below the tolerance.
+    fn is_zero(&self) -> bool;
+
+    // (a * b - c * d) / e when the division is exact, the step of the
+    // fraction-free elimination. Primitive integers compute the products
+    // in a wider type, so that they only overflow when the result does.
+    fn cross_quotient(a: Self, b: Self, c: Self, d: Self, e: Self) -> Self
+    where
+        Self: Sized + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self>,
+    {
+        return (a * b - c * d) / e;
+    }
+
+    // Relative rounding error of one operation: the machine epsilon for
+    // floating points, zero for exact scalars, whose cells are only
+    // negligible when they are exactly zero.
+    fn epsilon() -> Self
+    where
+        Self: Sized + Default,
+    {
+        return Self::default();
+    }
 }
 
 impl Operations for f32 {
@@ -15,6 +42,18 @@ impl Operations for f32 {
     fn sqrt(&self) -> Self {
         return self.powf(0.5);
     }
+
+    fn one() -> Self {
+        return 1.;
+    }
+
+    fn is_zero(&self) -> bool {
+        return self.abs() <= 1e-5;
+    }
+
+    fn epsilon() -> Self {
+        return f32::EPSILON;
+    }
 }
 
 impl Operations for f64 {
@@ -28,6 +67,18 @@ impl Operations for f64 {
     fn sqrt(&self) -> Self {
         return self.powf(0.5);
     }
+
+    fn one() -> Self {
+        return 1.;
+    }
+
+    fn is_zero(&self) -> bool {
+        return self.abs() <= 1e-10;
+    }
+
+    fn epsilon() -> Self {
+        return f64::EPSILON;
+    }
 }
 
 impl Operations for i32 {
@@ -41,6 +92,22 @@ impl Operations for i32 {
     fn sqrt(&self) -> Self {
         return (*self as f64).sqrt() as i32;
     }
+
+    fn one() -> Self {
+        return 1;
+    }
+
+    fn is_zero(&self) -> bool {
+        return *self == 0;
+    }
+
+    fn cross_quotient(a: Self, b: Self, c: Self, d: Self, e: Self) -> Self {
+        let quotient = (a as i64 * b as i64 - c as i64 * d as i64) / e as i64;
+        return match i32::try_from(quotient) {
+            Ok(quotient) => quotient,
+            Err(_) => panic!("{} does not fit in i32", quotient),
+        };
+    }
 }
 
 impl Operations for i64 {
@@ -54,6 +121,22 @@ impl Operations for i64 {
     fn sqrt(&self) -> Self {
         return (*self as f64).sqrt() as i64;
     }
+
+    fn one() -> Self {
+        return 1;
+    }
+
+    fn is_zero(&self) -> bool {
+        return *self == 0;
+    }
+
+    fn cross_quotient(a: Self, b: Self, c: Self, d: Self, e: Self) -> Self {
+        let quotient = (a as i128 * b as i128 - c as i128 * d as i128) / e as i128;
+        return match i64::try_from(quotient) {
+            Ok(quotient) => quotient,
+            Err(_) => panic!("{} does not fit in i64", quotient),
+        };
+    }
 }
 
 impl Operations for i128 {
@@ -67,6 +150,14 @@ impl Operations for i128 {
     fn sqrt(&self) -> Self {
         return (*self as f64).sqrt() as i128;
     }
+
+    fn one() -> Self {
+        return 1;
+    }
+
+    fn is_zero(&self) -> bool {
+        return *self == 0;
+    }
 }
 
 impl Operations for u32 {
@@ -77,6 +168,22 @@ impl Operations for u32 {
     fn sqrt(&self) -> Self {
         return (*self as f64).sqrt() as u32;
     }
+
+    fn one() -> Self {
+        return 1;
+    }
+
+    fn is_zero(&self) -> bool {
+        return *self == 0;
+    }
+
+    fn cross_quotient(a: Self, b: Self, c: Self, d: Self, e: Self) -> Self {
+        let quotient = (a as i64 * b as i64 - c as i64 * d as i64) / e as i64;
+        return match u32::try_from(quotient) {
+            Ok(quotient) => quotient,
+            Err(_) => panic!("{} does not fit in u32", quotient),
+        };
+    }
 }
 
 impl Operations for u64 {
@@ -87,6 +194,22 @@ impl Operations for u64 {
     fn sqrt(&self) -> Self {
         return (*self as f64).sqrt() as u64;
     }
+
+    fn one() -> Self {
+        return 1;
+    }
+
+    fn is_zero(&self) -> bool {
+        return *self == 0;
+    }
+
+    fn cross_quotient(a: Self, b: Self, c: Self, d: Self, e: Self) -> Self {
+        let quotient = (a as i128 * b as i128 - c as i128 * d as i128) / e as i128;
+        return match u64::try_from(quotient) {
+            Ok(quotient) => quotient,
+            Err(_) => panic!("{} does not fit in u64", quotient),
+        };
+    }
 }
 
 impl Operations for u128 {
@@ -97,5 +220,94 @@ impl Operations for u128 {
     fn sqrt(&self) -> Self {
         return (*self as f64).sqrt() as u128;
     }
+
+    fn one() -> Self {
+        return 1;
+    }
+
+    fn is_zero(&self) -> bool {
+        return *self == 0;
+    }
+}
+
+// Scalars where every non-zero element can be divided by,
+// required by the eliminations that divide by their pivot.
+// Integers are left out: their division truncates (see Matrix::bareiss).
+pub trait Field {}
+
+impl Field for f32 {}
+
+impl Field for f64 {}
+
+// Whether the division of K truncates, as for integers (1 / 2 == 0), rather
+// than being exact as in a field. Only then can a quotient be non-exact:
+// in a field it is exact, up to rounding for floating points.
+// A field of characteristic 2 has no 2 to divide by.
+pub fn truncating_division<
+    K: Add<Output = K> + Div<Output = K> + PartialEq + Default + Operations
+>() -> bool {
+    let two = K::one() + K::one();
+    return two != K::default() && K::one() / two == K::default();
+}
+
+// Floating point functions needed by geometry (angles, rotations, projections)
+pub trait Trigonometry {
+    fn sin(&self) -> Self;
+    fn cos(&self) -> Self;
+    fn tan(&self) -> Self;
+    fn acos(&self) -> Self;
+    fn atan2(&self, x: Self) -> Self;
+    fn from_f64(value: f64) -> Self;
+}
+
+impl Trigonometry for f32 {
+    fn sin(&self) -> Self {
+        return f32::sin(*self);
+    }
+
+    fn cos(&self) -> Self {
+        return f32::cos(*self);
+    }
+
+    fn tan(&self) -> Self {
+        return f32::tan(*self);
+    }
+
+    fn acos(&self) -> Self {
+        return f32::acos(self.clamp(-1., 1.));
+    }
+
+    fn atan2(&self, x: Self) -> Self {
+        return f32::atan2(*self, x);
+    }
+
+    fn from_f64(value: f64) -> Self {
+        return value as f32;
+    }
 }
 
+impl Trigonometry for f64 {
+    fn sin(&self) -> Self {
+        return f64::sin(*self);
+    }
+
+    fn cos(&self) -> Self {
+        return f64::cos(*self);
+    }
+
+    fn tan(&self) -> Self {
+        return f64::tan(*self);
+    }
+
+    fn acos(&self) -> Self {
+        return f64::acos(self.clamp(-1., 1.));
+    }
+
+    fn atan2(&self, x: Self) -> Self {
+        return f64::atan2(*self, x);
+    }
+
+    fn from_f64(value: f64) -> Self {
+        return value;
+    }
+}
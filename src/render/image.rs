@@ -0,0 +1,115 @@
+// RGB raster, stored row by row from the top-left corner
+#[derive(Clone, PartialEq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<[u8; 3]>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize) -> Image {
+        return Image {
+            width,
+            height,
+            pixels: vec![[0, 0, 0]; width * height],
+        };
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        return (self.width, self.height);
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> [u8; 3] {
+        return self.pixels[y * self.width + x];
+    }
+
+    // Pixels outside of the image are ignored
+    pub fn set(&mut self, x: i64, y: i64, color: [u8; 3]) {
+        if x < 0 || y < 0 || x >= self.width as i64 || y >= self.height as i64 {
+            return;
+        }
+        self.pixels[y as usize * self.width + x as usize] = color;
+    }
+
+    // Binary PPM (P6)
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut out = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        for pixel in &self.pixels {
+            out.extend_from_slice(pixel);
+        }
+        return out;
+    }
+
+    // Binary PGM (P5), with the Rec. 601 luma of each pixel
+    pub fn to_pgm(&self) -> Vec<u8> {
+        let mut out = format!("P5\n{} {}\n255\n", self.width, self.height).into_bytes();
+        for p in &self.pixels {
+            let luma = 0.299 * p[0] as f32 + 0.587 * p[1] as f32 + 0.114 * p[2] as f32;
+            out.push(luma.round() as u8);
+        }
+        return out;
+    }
+
+    // Reads a binary PPM (P6) or PGM (P5) with a maxval of 255,
+    // e.g. a golden image
+    pub fn from_pnm(bytes: &[u8]) -> Result<Image, &'static str> {
+        // Magic number, width, height and maxval, separated by whitespace
+        let mut header: Vec<String> = Vec::new();
+        let mut i = 0;
+        while header.len() < 4 {
+            while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+                i += 1;
+            }
+            let start = i;
+            while i < bytes.len() && !bytes[i].is_ascii_whitespace() {
+                i += 1;
+            }
+            if start == i {
+                return Err("Truncated header");
+            }
+            header.push(String::from_utf8_lossy(&bytes[start..i]).to_string());
+        }
+        // A single whitespace separates the header from the data
+        i += 1;
+
+        let channels = match header[0].as_str() {
+            "P6" => 3,
+            "P5" => 1,
+            _ => return Err("Only binary PPM (P6) and PGM (P5) are supported"),
+        };
+        let width: usize = header[1].parse().map_err(|_| "Invalid width")?;
+        let height: usize = header[2].parse().map_err(|_| "Invalid height")?;
+        if header[3] != "255" {
+            return Err("Only a maxval of 255 is supported");
+        }
+        if bytes.len() < i + width * height * channels {
+            return Err("Truncated data");
+        }
+
+        let mut image = Image::new(width, height);
+        for p in 0..width * height {
+            let at = i + p * channels;
+            image.pixels[p] = if channels == 3 {
+                [bytes[at], bytes[at + 1], bytes[at + 2]]
+            } else {
+                [bytes[at]; 3]
+            };
+        }
+        return Ok(image);
+    }
+
+    // Number of pixels that differ by more than `tolerance` on any channel,
+    // None if the images do not have the same size
+    pub fn diff(&self, other: &Image, tolerance: u8) -> Option<usize> {
+        if self.shape() != other.shape() {
+            return None;
+        }
+        let count = self
+            .pixels
+            .iter()
+            .zip(&other.pixels)
+            .filter(|(a, b)| (0..3).any(|c| a[c].abs_diff(b[c]) > tolerance))
+            .count();
+        return Some(count);
+    }
+}
@@ -0,0 +1,71 @@
+use crate::core::Vector;
+
+// Triangle mesh, faces index into vertices
+pub struct Mesh {
+    pub vertices: Vec<Vector<f32>>,
+    pub faces: Vec<[usize; 3]>,
+}
+
+impl Mesh {
+    // Parses the subset of Wavefront OBJ made of
+    // `v x y z [w]` and `f a b c ...` statements.
+    // Face indices may be negative (relative) or of the form a/t/n,
+    // polygons are split into a fan of triangles, the rest is ignored.
+    // Time: O(n) − Space: O(n)
+    // where n is the length of the source
+    pub fn from_obj(source: &str) -> Result<Mesh, String> {
+        let mut vertices: Vec<Vector<f32>> = Vec::new();
+        let mut faces: Vec<[usize; 3]> = Vec::new();
+
+        for (number, line) in source.lines().enumerate() {
+            let mut tokens = line.split_whitespace();
+            match tokens.next() {
+                Some("v") => {
+                    let coordinates: Vec<f32> = tokens
+                        .map(|t| t.parse::<f32>())
+                        .collect::<Result<_, _>>()
+                        .map_err(|_| format!("line {}: invalid vertex", number + 1))?;
+                    if coordinates.len() < 3 {
+                        return Err(format!("line {}: a vertex needs 3 coordinates", number + 1));
+                    }
+                    let w = if coordinates.len() > 3 { coordinates[3] } else { 1. };
+                    vertices.push(Vector::from(vec![
+                        coordinates[0] / w,
+                        coordinates[1] / w,
+                        coordinates[2] / w,
+                    ]));
+                }
+                Some("f") => {
+                    let mut polygon: Vec<usize> = Vec::new();
+                    for token in tokens {
+                        let index = token
+                            .split('/')
+                            .next()
+                            .unwrap_or("")
+                            .parse::<i64>()
+                            .map_err(|_| format!("line {}: invalid face", number + 1))?;
+                        // OBJ indices start at 1, negative ones count from the end
+                        let resolved = if index > 0 {
+                            index - 1
+                        } else {
+                            vertices.len() as i64 + index
+                        };
+                        if resolved < 0 || resolved >= vertices.len() as i64 {
+                            return Err(format!("line {}: unknown vertex {}", number + 1, index));
+                        }
+                        polygon.push(resolved as usize);
+                    }
+                    if polygon.len() < 3 {
+                        return Err(format!("line {}: a face needs 3 vertices", number + 1));
+                    }
+                    for i in 1..polygon.len() - 1 {
+                        faces.push([polygon[0], polygon[i], polygon[i + 1]]);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        return Ok(Mesh { vertices, faces });
+    }
+}
@@ -0,0 +1,8 @@
+pub mod mesh;
+pub mod image;
+#[allow(clippy::module_inception)]
+pub mod render;
+
+pub use mesh::*;
+pub use image::*;
+pub use render::*;
@@ -0,0 +1,250 @@
+// Headless rasterizer, to check the transform and projection code end-to-end.
+// Clip space follows the OpenGL convention (-w <= x, y, z <= w),
+// as produced by transform::Frustum with Depth::NegativeOneToOne.
+use crate::core::{Matrix, Vector};
+use crate::render::image::Image;
+use crate::render::mesh::Mesh;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Mode {
+    // Edges of every triangle
+    Wireframe,
+    // Front-facing triangles lit by a light at the camera, with a depth buffer
+    Flat,
+}
+
+pub struct Scene<'a> {
+    pub mesh: &'a Mesh,
+    pub model: Matrix<f32>,
+    pub view: Matrix<f32>,
+    pub projection: Matrix<f32>,
+    pub color: [u8; 3],
+}
+
+type Homogeneous = [f32; 4];
+
+// Signed distances to the six clip planes, inside when all are positive
+fn plane_distances(p: &Homogeneous) -> [f32; 6] {
+    return [
+        p[3] + p[0],
+        p[3] - p[0],
+        p[3] + p[1],
+        p[3] - p[1],
+        p[3] + p[2],
+        p[3] - p[2],
+    ];
+}
+
+fn interpolate(a: &Homogeneous, b: &Homogeneous, t: f32) -> Homogeneous {
+    return [
+        a[0] + (b[0] - a[0]) * t,
+        a[1] + (b[1] - a[1]) * t,
+        a[2] + (b[2] - a[2]) * t,
+        a[3] + (b[3] - a[3]) * t,
+    ];
+}
+
+// Liang-Barsky clipping of a segment against the clip volume
+fn clip_line(a: &Homogeneous, b: &Homogeneous) -> Option<(Homogeneous, Homogeneous)> {
+    let (da, db) = (plane_distances(a), plane_distances(b));
+    let (mut t0, mut t1): (f32, f32) = (0., 1.);
+
+    for i in 0..6 {
+        if da[i] < 0. && db[i] < 0. {
+            return None;
+        }
+        if da[i] < 0. {
+            t0 = t0.max(da[i] / (da[i] - db[i]));
+        } else if db[i] < 0. {
+            t1 = t1.min(da[i] / (da[i] - db[i]));
+        }
+    }
+
+    if t0 > t1 {
+        return None;
+    }
+    return Some((interpolate(a, b, t0), interpolate(a, b, t1)));
+}
+
+// Sutherland-Hodgman clipping of a convex polygon against the clip volume
+fn clip_polygon(polygon: Vec<Homogeneous>) -> Vec<Homogeneous> {
+    let mut output = polygon;
+
+    for plane in 0..6 {
+        let input = output;
+        output = Vec::new();
+        for i in 0..input.len() {
+            let (a, b) = (&input[i], &input[(i + 1) % input.len()]);
+            let (da, db) = (plane_distances(a)[plane], plane_distances(b)[plane]);
+            if da >= 0. {
+                output.push(*a);
+            }
+            if (da >= 0.) != (db >= 0.) {
+                output.push(interpolate(a, b, da / (da - db)));
+            }
+        }
+        if output.is_empty() {
+            break;
+        }
+    }
+
+    return output;
+}
+
+// Perspective divide, then NDC to pixels (y pointing down).
+// The depth stays in NDC.
+fn to_screen(p: &Homogeneous, width: usize, height: usize) -> [f32; 3] {
+    let (x, y, z) = (p[0] / p[3], p[1] / p[3], p[2] / p[3]);
+    return [
+        (x + 1.) / 2. * width as f32,
+        (1. - y) / 2. * height as f32,
+        z,
+    ];
+}
+
+// Bresenham's line algorithm
+fn draw_line(image: &mut Image, a: [f32; 3], b: [f32; 3], color: [u8; 3]) {
+    let (mut x0, mut y0) = (a[0].floor() as i64, a[1].floor() as i64);
+    let (x1, y1) = (b[0].floor() as i64, b[1].floor() as i64);
+    let (dx, dy) = ((x1 - x0).abs(), -(y1 - y0).abs());
+    let (sx, sy) = (if x0 < x1 { 1 } else { -1 }, if y0 < y1 { 1 } else { -1 });
+    let mut error = dx + dy;
+
+    loop {
+        image.set(x0, y0, color);
+        if x0 == x1 && y0 == y1 {
+            break;
+        }
+        let doubled = 2 * error;
+        if doubled >= dy {
+            error += dy;
+            x0 += sx;
+        }
+        if doubled <= dx {
+            error += dx;
+            y0 += sy;
+        }
+    }
+}
+
+fn edge(a: &[f32; 3], b: &[f32; 3], x: f32, y: f32) -> f32 {
+    return (b[0] - a[0]) * (y - a[1]) - (b[1] - a[1]) * (x - a[0]);
+}
+
+// Fills the pixels whose centre is inside the triangle and closer than the depth buffer
+fn fill_triangle(
+    image: &mut Image,
+    depth: &mut [f32],
+    t: [[f32; 3]; 3],
+    color: [u8; 3],
+) {
+    let (width, height) = image.shape();
+    let area = edge(&t[0], &t[1], t[2][0], t[2][1]);
+    if area == 0. {
+        return;
+    }
+
+    let min_x = t.iter().map(|p| p[0]).fold(f32::INFINITY, f32::min).floor().max(0.) as usize;
+    let max_x = t.iter().map(|p| p[0]).fold(f32::NEG_INFINITY, f32::max).ceil() as usize;
+    let min_y = t.iter().map(|p| p[1]).fold(f32::INFINITY, f32::min).floor().max(0.) as usize;
+    let max_y = t.iter().map(|p| p[1]).fold(f32::NEG_INFINITY, f32::max).ceil() as usize;
+
+    for y in min_y..max_y.min(height) {
+        for x in min_x..max_x.min(width) {
+            let (px, py) = (x as f32 + 0.5, y as f32 + 0.5);
+            let w0 = edge(&t[1], &t[2], px, py) / area;
+            let w1 = edge(&t[2], &t[0], px, py) / area;
+            let w2 = edge(&t[0], &t[1], px, py) / area;
+            if w0 < 0. || w1 < 0. || w2 < 0. {
+                continue;
+            }
+            // NDC depth is affine in screen space
+            let z = w0 * t[0][2] + w1 * t[1][2] + w2 * t[2][2];
+            if z < depth[y * width + x] {
+                depth[y * width + x] = z;
+                image.set(x as i64, y as i64, color);
+            }
+        }
+    }
+}
+
+fn apply(m: &Matrix<f32>, v: &Vector<f32>) -> Homogeneous {
+    let mut h = v.flat();
+    h.push(1.);
+    let p = m.mul_vec(Vector::from(h)).flat();
+    return [p[0], p[1], p[2], p[3]];
+}
+
+// Time: O(f + wh) − Space: O(v + wh)
+// where f is the number of faces, v the number of vertices and w * h the image size
+pub fn render(scene: &Scene, mode: Mode, width: usize, height: usize) -> Result<Image, &'static str> {
+    for m in [&scene.model, &scene.view, &scene.projection] {
+        if m.shape() != (4, 4) {
+            return Err("The transforms must have shape (4, 4)");
+        }
+    }
+
+    let model_view = scene.view.mul_mat(scene.model.clone());
+    let mvp = scene.projection.mul_mat(model_view.clone());
+    let eye: Vec<Homogeneous> = scene.mesh.vertices.iter().map(|v| apply(&model_view, v)).collect();
+    let clip: Vec<Homogeneous> = scene.mesh.vertices.iter().map(|v| apply(&mvp, v)).collect();
+
+    let mut image = Image::new(width, height);
+    let mut depth: Vec<f32> = vec![f32::INFINITY; width * height];
+
+    for face in &scene.mesh.faces {
+        match mode {
+            Mode::Wireframe => {
+                for i in 0..3 {
+                    let (a, b) = (&clip[face[i]], &clip[face[(i + 1) % 3]]);
+                    if let Some((a, b)) = clip_line(a, b) {
+                        let a = to_screen(&a, width, height);
+                        let b = to_screen(&b, width, height);
+                        draw_line(&mut image, a, b, scene.color);
+                    }
+                }
+            }
+            Mode::Flat => {
+                // Face normal in eye space, the light shines from the camera
+                let p: Vec<Vector<f32>> = face
+                    .iter()
+                    .map(|&i| Vector::from(vec![eye[i][0], eye[i][1], eye[i][2]]))
+                    .collect();
+                let mut u = p[1].clone();
+                u.sub(&p[0]);
+                let mut v = p[2].clone();
+                v.sub(&p[0]);
+                let normal = match u.cross(&v)?.normalize() {
+                    Ok(normal) => normal,
+                    Err(_) => continue,
+                };
+                let mut to_camera = p[0].clone();
+                to_camera.scl(-1.);
+                let lambert = match to_camera.normalize() {
+                    Ok(direction) => normal.dot(direction).max(0.),
+                    Err(_) => 0.,
+                };
+                let intensity = 0.2 + 0.8 * lambert;
+                let color = scene.color.map(|c| (c as f32 * intensity).round() as u8);
+
+                let polygon = clip_polygon(face.iter().map(|&i| clip[i]).collect());
+                if polygon.len() < 3 {
+                    continue;
+                }
+                let screen: Vec<[f32; 3]> =
+                    polygon.iter().map(|p| to_screen(p, width, height)).collect();
+                for i in 1..screen.len() - 1 {
+                    let t = [screen[0], screen[i], screen[i + 1]];
+                    // Counter-clockwise in NDC is clockwise once y points down:
+                    // cull the back faces
+                    if edge(&t[0], &t[1], t[2][0], t[2][1]) > 0. {
+                        continue;
+                    }
+                    fill_triangle(&mut image, &mut depth, t, color);
+                }
+            }
+        }
+    }
+
+    return Ok(image);
+}
@@ -14,6 +14,31 @@ pub mod test_12;
 pub mod test_13;
 pub mod test_14;
 pub mod test_15;
+pub mod test_16;
+pub mod test_17;
+pub mod test_18;
+pub mod test_19;
+pub mod test_20;
+pub mod test_21;
+pub mod test_22;
+pub mod test_23;
+pub mod test_24;
+pub mod test_25;
+pub mod test_26;
+pub mod test_27;
+pub mod test_28;
+pub mod test_29;
+pub mod test_30;
+pub mod test_31;
+pub mod test_32;
+pub mod test_33;
+pub mod test_34;
+pub mod test_35;
+pub mod test_36;
+pub mod test_37;
+pub mod test_38;
+pub mod test_39;
+pub mod test_40;
 
 pub use test_00::test_00;
 pub use test_01::test_01;
@@ -31,3 +56,28 @@ pub use test_12::test_12;
 pub use test_13::test_13;
 pub use test_14::test_14;
 pub use test_15::test_15;
+pub use test_16::test_16;
+pub use test_17::test_17;
+pub use test_18::test_18;
+pub use test_19::test_19;
+pub use test_20::test_20;
+pub use test_21::test_21;
+pub use test_22::test_22;
+pub use test_23::test_23;
+pub use test_24::test_24;
+pub use test_25::test_25;
+pub use test_26::test_26;
+pub use test_27::test_27;
+pub use test_28::test_28;
+pub use test_29::test_29;
+pub use test_30::test_30;
+pub use test_31::test_31;
+pub use test_32::test_32;
+pub use test_33::test_33;
+pub use test_34::test_34;
+pub use test_35::test_35;
+pub use test_36::test_36;
+pub use test_37::test_37;
+pub use test_38::test_38;
+pub use test_39::test_39;
+pub use test_40::test_40;
@@ -1,17 +1,4 @@
-use crate::core::{Vector, Matrix};
-
-pub fn lerp<
-    V: std::fmt::Display
-        + std::ops::Add<Output = V>
-        + std::ops::Sub<Output = V>
-        + std::ops::Mul<f32, Output = V>
->(
-    u: V,
-    v: V,
-    t: f32,
-) -> V {
-    return u * (1. - t) + v * t;
-}
+use crate::core::{Vector, Matrix, lerp};
 
 pub fn test_02() {
     println!("{}\n", lerp(0., 1., 0.));
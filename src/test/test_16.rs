@@ -0,0 +1,39 @@
+use crate::core::{Matrix, RowTrace};
+
+pub fn test_16() {
+    let u = Matrix::from(vec![vec![0., 2.], vec![3., 4.]]);
+    let trace: RowTrace<f64> = u.row_echelon_trace();
+    println!("{}", trace.to_text());
+    // [0, 2]
+    // [3, 4]
+    //
+    // Step 1: R1 <-> R2
+    // ...
+    // Step 4: R1 <- R1 - 1.3333333333333333 * R2
+    // E =
+    // [1, -1.3333333333333333]
+    // [0, 1]
+    // =>
+    // [1, 0]
+    // [0, 1]
+    println!("{}\n", trace.transform().mul_mat(u.clone()));
+    // [1, 0]
+    // [0, 1]
+    println!("{}\n", trace.result().flat() == trace.transform().mul_mat(u.clone()).flat());
+    // true
+
+    let u = Matrix::from(vec![
+        vec![8., 5., -2.],
+        vec![4., 7., 20.],
+        vec![7., 6., 1.],
+    ]);
+    let trace = u.row_echelon_trace();
+    println!("{}", trace.to_colored());
+    println!("{}\n", trace.to_latex());
+    // \begin{bmatrix}
+    // 8 & 5 & -2 \\
+    // ...
+    // \end{bmatrix}
+    // \xrightarrow{R_{1} \to 0.125 R_{1}}
+    // ...
+}
@@ -0,0 +1,44 @@
+use crate::core::{Matrix, Vector, Echelon};
+
+pub fn test_17() {
+    let u = Matrix::from(vec![
+        vec![1., 2., 0., 0.],
+        vec![2., 4., 0., 0.],
+        vec![-1., 2., 1., 1.]
+    ]);
+    let r: Echelon<f64> = u.row_echelon_form();
+    println!("{}\n{:?} {:?} {:?} {}\n", r.matrix, r.pivots, r.free_columns, r.permutation, r.scale);
+    // [2, 4, 0, 0]
+    // [0, 4, 1, 1]
+    // [0, 0, 0, 0]
+    // [(0, 0), (1, 1)] [2, 3] [1, 2, 0] 1
+    let b = u.bareiss();
+    println!("{:?} {:?}\n", b.pivot_columns(), b.free_columns);
+    // [0, 1] [2, 3]
+    let r = u.reduced_row_echelon_form();
+    println!("{}\n{:?} {:?} {:?} {}\n", r.matrix, r.pivots, r.free_columns, r.permutation, r.scale);
+    // [1, 0, -0.5, -0.5]
+    // [0, 1, 0.25, 0.25]
+    // [0, 0, 0, 0]
+    // [(0, 0), (1, 1)] [2, 3] [1, 2, 0] 0.125
+
+    let u = Matrix::from(vec![
+        vec![8., 5., -2.],
+        vec![4., 7., 20.],
+        vec![7., 6., 1.]
+    ]);
+    let r = u.row_echelon_form();
+    let mut det = 1.;
+    for &(row, column) in &r.pivots {
+        det *= r.matrix.get(row, column);
+    }
+    println!("{}\n", det / r.scale);
+    // -174.0
+
+    // Pivots are compared with a threshold relative to their column,
+    // so a tiny but regular matrix keeps all of them
+    let tiny: Matrix<f64> = Matrix::from(vec![vec![1e-12, 0.], vec![0., 1e-12]]);
+    let x = tiny.solve(&Vector::from(vec![1e-12, 1e-12])).unwrap();
+    println!("{} {:?}\n", tiny.reduced_row_echelon_form().rank(), x.flat());
+    // 2 [1.0, 1.0]
+}
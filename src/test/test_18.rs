@@ -0,0 +1,54 @@
+use crate::core::Matrix;
+
+// rank + nullity == n, and A v is rounding residue for every null vector
+fn check(u: &Matrix<f64>) {
+    let n = u.shape().1;
+    let kernel = u.null_space();
+    let largest = u.flat().iter().fold(0., |m: f64, x| m.max(x.abs()));
+    let in_kernel = kernel.iter().all(|v| {
+        u.mul_vec(v.clone()).flat().iter().all(|x| x.abs() <= 1e-12 * largest)
+    });
+    println!(
+        "{} + {} = {} with {} null vectors, all mapped to ~0: {}\n",
+        u.rank(),
+        u.nullity(),
+        n,
+        kernel.len(),
+        in_kernel
+    );
+}
+
+pub fn test_18() {
+    let u = Matrix::from(vec![
+        vec![1., 2., 0., 0.],
+        vec![2., 4., 0., 0.],
+        vec![-1., 2., 1., 1.]
+    ]);
+    for v in u.null_space() {
+        println!("{}\n", v);
+        println!("{}\n", u.mul_vec(v.clone()));
+        // [0]
+        // [0]
+        // [0]
+    }
+    for v in u.column_space() {
+        println!("{}\n", v);
+    }
+    for v in u.row_space() {
+        println!("{}\n", v);
+    }
+    for v in u.left_null_space() {
+        println!("{}\n", v);
+        // [-2]
+        // [1]
+        // [0]
+    }
+    println!("{} + {} = {}\n", u.reduced_row_echelon_form().rank(), u.nullity(), u.shape().1);
+    // 2 + 2 = 4
+    check(&u);
+    // 2 + 2 = 4 with 2 null vectors, all mapped to ~0: true
+    check(&Matrix::from(vec![vec![0.1, 0.2, 0.3], vec![0.4, 0.5, 0.6], vec![0.7, 0.8, 0.9]]));
+    // 2 + 1 = 3 with 1 null vectors, all mapped to ~0: true
+    check(&Matrix::from(vec![vec![1e-12, 0.], vec![0., 1e-12]]));
+    // 2 + 0 = 2 with 0 null vectors, all mapped to ~0: true
+}
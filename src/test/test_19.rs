@@ -0,0 +1,38 @@
+use crate::core::{
+    Vector, is_linearly_independent, span_contains,
+    extract_basis, extend_to_basis, change_of_basis,
+};
+
+pub fn test_19() {
+    let v1 = Vector::from(vec![1., 2., 3.]);
+    let v2 = Vector::from(vec![0., 10., -100.]);
+    let v3 = Vector::from(vec![2., 14., -94.]);
+    println!("{}\n", is_linearly_independent(&[v1.clone(), v2.clone()]));
+    // true
+    println!("{}\n", is_linearly_independent(&[v1.clone(), v2.clone(), v3.clone()]));
+    // false
+    println!("{:?}\n", span_contains(&[v1.clone(), v2.clone()], &v3));
+    // Some([2.0, 1.0]) (up to rounding)
+    println!("{:?}\n", span_contains(&[v1.clone(), v2.clone()], &Vector::from(vec![0., 0., 1.])));
+    // None
+
+    let basis = extract_basis(&[v1.clone(), v3.clone(), v2.clone()]);
+    println!("{}\n", basis.len());
+    // 2
+    for v in extend_to_basis(&basis, 3) {
+        println!("{}\n", v);
+    }
+    // [1] [2] [3], [2] [14] [-94], [1] [0] [0]
+
+    let e = [
+        Vector::from(vec![1., 0.]),
+        Vector::from(vec![0., 1.]),
+    ];
+    let b = [
+        Vector::from(vec![1., 1.]),
+        Vector::from(vec![1., -1.]),
+    ];
+    println!("{}\n", change_of_basis(&e, &b).unwrap());
+    // [0.5, 0.5]
+    // [0.5, -0.5]
+}
@@ -0,0 +1,53 @@
+use crate::core::Vector;
+
+pub fn test_20() {
+    let u: Vector<f64> = Vector::from(vec![3., 4., 0.]);
+    let v: Vector<f64> = Vector::from(vec![1., 0., 0.]);
+    let w: Vector<f64> = Vector::from(vec![0., 1., 0.]);
+
+    println!("{}\n", u.normalize().unwrap());
+    // [0.6]
+    // [0.8]
+    // [0]
+    println!("{}\n", v.angle(&w).unwrap());
+    // 1.5707963267948966
+    println!("{}\n", u.project_onto(&v).unwrap());
+    // [3]
+    // [0]
+    // [0]
+    println!("{}\n", u.reject_from(&v).unwrap());
+    // [0]
+    // [4]
+    // [0]
+    println!("{}\n", u.reflect(&v).unwrap());
+    // [-3]
+    // [4]
+    // [0]
+    println!("{}\n", u.distance(&v).unwrap());
+    // 4.47213595499958
+    println!("{}\n", v.outer(&w));
+    // [0, 1, 0]
+    // [0, 0, 0]
+    // [0, 0, 0]
+    println!("{}\n", Vector::from(vec![0., 0., 1.]).triple_product(&v, &w).unwrap());
+    // 1
+    println!("{}\n", v.vector_triple_product(&v, &w).unwrap());
+    // [0]
+    // [-1]
+    // [0]
+    println!("{}\n", v.slerp(&w, 0.5).unwrap());
+    // [0.7071067811865475]
+    // [0.7071067811865475]
+    // [0]
+    let x: Vector<f32> = Vector::from(vec![1., 0.]);
+    let y: Vector<f32> = Vector::from(vec![0., 1.]);
+    println!("{} {}\n", x.angle(&y).unwrap(), x.slerp(&y, 1. / 3.).unwrap().flat()[1]);
+    // 1.5707964 0.5
+    println!("{:?}\n", Vector::from(vec![0., 0.]).normalize().err());
+    // Some("The zero vector cannot be normalized")
+    // Only the exact zero vector is refused
+    let small: Vector<f64> = Vector::from(vec![1e-11, 0.]);
+    let small_f32: Vector<f32> = Vector::from(vec![1e-6, 0.]);
+    println!("{:?} {:?}\n", small.normalize().unwrap().flat(), small_f32.normalize().unwrap().flat());
+    // [0.9999999999999999, 0.0] [1.0, 0.0]
+}
@@ -0,0 +1,66 @@
+use crate::core::{Matrix, Vector, SMatrix, SVector};
+
+pub fn test_21() {
+    let u: SMatrix<f64, 3, 3> = SMatrix::from([
+        [8., 5., -2.],
+        [4., 7., 20.],
+        [7., 6., 1.],
+    ]);
+    println!("{}\n", u.determinant());
+    // -174
+    println!("{}\n", u.inverse().unwrap());
+    // [0.649425287, 0.097701149, -0.655172414]
+    // [-0.781609195, -0.126436782, 0.965517241]
+    // [0.143678161, 0.074712644, -0.206896552]
+    let small: SMatrix<f64, 3, 3> = SMatrix::from([[1e-6, 0., 0.], [0., 1e-6, 0.], [0., 0., 1e-6]]);
+    println!("{}\n", small.inverse().unwrap().trace());
+    // 3000000 (up to rounding), a tiny determinant is not a singular matrix
+
+    let u: SMatrix<f64, 4, 4> = SMatrix::from([
+        [8., 5., -2., 4.],
+        [4., 2.5, 20., 4.],
+        [8., 5., 1., 4.],
+        [28., -4., 17., 1.],
+    ]);
+    println!("{}\n", u.determinant());
+    // 1032
+    println!("{}\n", u * u.inverse().unwrap());
+    // Identity (up to rounding)
+
+    // (2, 3) * (3, 1): any other shape would not compile
+    let u: SMatrix<f64, 2, 3> = SMatrix::from([[1., 2., 3.], [4., 5., 6.]]);
+    let v: SVector<f64, 3> = SVector::from([1., 0., -1.]);
+    println!("{}\n", u * v);
+    // [-2]
+    // [-2]
+
+    let dynamic: Matrix<f64> = Matrix::from(u);
+    println!("{}\n", dynamic.shape().0 * dynamic.shape().1);
+    // 6
+    let back: SMatrix<f64, 2, 3> = SMatrix::try_from(&dynamic).unwrap();
+    println!("{}\n", back == u);
+    // true
+    println!("{:?}\n", SMatrix::<f64, 3, 2>::try_from(&dynamic).err());
+    // Some("The shape of the matrix does not match")
+    println!("{:?} {}\n", u.shape(), v.dot(&v));
+    // (2, 3) 2
+
+    let mut m: SMatrix<f64, 2, 2> = SMatrix::zeros();
+    m.set(0, 0, 4.);
+    m.set(0, 1, 7.);
+    m.set(1, 0, 2.);
+    m.set(1, 1, 6.);
+    println!("{} {} {}\n", m.get(1, 0), m.trace(), m.determinant());
+    // 2 10 10
+    println!("{}\n", m.inverse().unwrap());
+    // [0.6, -0.7]
+    // [-0.2, 0.4]
+    // (up to rounding)
+    println!("{}\n", m.transpose() * SMatrix::identity());
+    // [4, 2]
+    // [7, 6]
+    println!("{}\n", Vector::from(v));
+    // [1]
+    // [0]
+    // [-1]
+}
@@ -0,0 +1,47 @@
+use crate::core::Vector;
+use crate::transform::{
+    translation, scaling, rotation_z, rotation, look_at, orthographic,
+    transform_point, transform_direction,
+};
+
+pub fn test_22() {
+    let p: Vector<f32> = Vector::from(vec![1., 0., 0.]);
+
+    let m = translation(1., 2., 3.);
+    println!("{}\n", transform_point(&m, &p).unwrap());
+    // [2]
+    // [2]
+    // [3]
+    println!("{}\n", transform_direction(&m, &p).unwrap());
+    // [1]
+    // [0]
+    // [0]
+
+    let m = rotation_z(std::f32::consts::FRAC_PI_2).mul_mat(scaling(2., 2., 2.));
+    println!("{}\n", transform_point(&m, &p).unwrap());
+    // [0] (up to rounding)
+    // [2]
+    // [0]
+
+    let axis = Vector::from(vec![1., 1., 1.]);
+    let m = rotation(&axis, 2. * std::f32::consts::FRAC_PI_3).unwrap();
+    println!("{}\n", transform_point(&m, &p).unwrap());
+    // [0] (up to rounding)
+    // [1]
+    // [0]
+
+    let eye = Vector::from(vec![0., 0., 5.]);
+    let target = Vector::from(vec![0., 0., 0.]);
+    let up = Vector::from(vec![0., 1., 0.]);
+    let view = look_at(&eye, &target, &up).unwrap();
+    println!("{}\n", transform_point(&view, &target).unwrap());
+    // [0]
+    // [0]
+    // [-5]
+
+    let m = orthographic(-2., 2., -1., 1., 1., 11.).unwrap();
+    println!("{}\n", transform_point(&m, &Vector::from(vec![2., 1., -11.])).unwrap());
+    // [1]
+    // [1]
+    // [1]
+}
@@ -0,0 +1,104 @@
+use crate::core::Matrix;
+use crate::transform::{Frustum, Depth, Layout, to_layout};
+use crate::test::test_14::projection;
+
+// Largest difference between the cells of m and the expected ones
+fn error(m: &Matrix<f64>, expected: [[f64; 4]; 4]) -> f64 {
+    let mut max: f64 = 0.;
+    for r in 0..4 {
+        for c in 0..4 {
+            max = max.max((m.get(r, c) - expected[r][c]).abs());
+        }
+    }
+    return max;
+}
+
+fn check(name: &str, frustum: Frustum<f64>, expected: [[f64; 4]; 4]) {
+    let m = frustum.matrix().unwrap();
+    let identity = [
+        [1., 0., 0., 0.],
+        [0., 1., 0., 0.],
+        [0., 0., 1., 0.],
+        [0., 0., 0., 1.],
+    ];
+    let inverse_error = error(&m.mul_mat(frustum.inverse().unwrap()), identity);
+    println!(
+        "{}: {}, inverse: {}",
+        name,
+        error(&m, expected) < 1e-12,
+        inverse_error < 1e-12
+    );
+}
+
+pub fn test_23() {
+    let fov = std::f64::consts::FRAC_PI_2;
+    let mut frustum = Frustum::perspective(fov, 1., 1., Some(3.));
+    check("OpenGL", frustum, [
+        [1., 0., 0., 0.],
+        [0., 1., 0., 0.],
+        [0., 0., -2., -3.],
+        [0., 0., -1., 0.],
+    ]);
+    // OpenGL: true, inverse: true
+
+    frustum.depth = Depth::ZeroToOne;
+    check("Vulkan", frustum, [
+        [1., 0., 0., 0.],
+        [0., 1., 0., 0.],
+        [0., 0., -1.5, -1.5],
+        [0., 0., -1., 0.],
+    ]);
+    // Vulkan: true, inverse: true
+
+    frustum.reversed = true;
+    check("Reversed-Z", frustum, [
+        [1., 0., 0., 0.],
+        [0., 1., 0., 0.],
+        [0., 0., 0.5, 1.5],
+        [0., 0., -1., 0.],
+    ]);
+    // Reversed-Z: true, inverse: true
+
+    frustum.far = None;
+    check("Reversed-Z infinite", frustum, [
+        [1., 0., 0., 0.],
+        [0., 1., 0., 0.],
+        [0., 0., 0., 1.],
+        [0., 0., -1., 0.],
+    ]);
+    // Reversed-Z infinite: true, inverse: true
+
+    let frustum = Frustum::perspective(fov, 1., 1., None);
+    check("OpenGL infinite", frustum, [
+        [1., 0., 0., 0.],
+        [0., 1., 0., 0.],
+        [0., 0., -1., -2.],
+        [0., 0., -1., 0.],
+    ]);
+    // OpenGL infinite: true, inverse: true
+
+    let frustum = Frustum::new(0., 2., -1., 1., 1., Some(3.));
+    check("Off-centre", frustum, [
+        [1., 0., 1., 0.],
+        [0., 1., 0., 0.],
+        [0., 0., -2., -3.],
+        [0., 0., -1., 0.],
+    ]);
+    // Off-centre: true, inverse: true
+
+    // The projection of exercise 14 is written in column-major order
+    let frustum: Frustum<f32> = Frustum::perspective(100f32.to_radians(), 3. / 2., 2., Some(50.));
+    let reference = projection(100., 3. / 2., 2., 50.);
+    let layout = to_layout(&frustum.matrix().unwrap(), Layout::ColumnMajor);
+    let same = layout
+        .iter()
+        .zip(reference.flat())
+        .all(|(a, b)| (a - b).abs() < 1e-5);
+    println!("Exercise 14: {}\n", same);
+    // Exercise 14: true
+    println!("{}\n", to_layout(&reference, Layout::RowMajor) == reference.flat());
+    // true
+
+    println!("{:?}\n", Frustum::new(-1., 1., -1., 1., 2., Some(1.)).matrix().err());
+    // Some("The far plane must be behind the near plane")
+}
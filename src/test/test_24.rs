@@ -0,0 +1,50 @@
+use crate::core::{Vector, Quaternion};
+use crate::transform::rotation;
+
+pub fn test_24() {
+    let i: Quaternion<f64> = Quaternion::new(0., 1., 0., 0.);
+    let j = Quaternion::new(0., 0., 1., 0.);
+    println!("{}\n", i * j);
+    // (0 + 0i + 0j + 1k)
+    println!("{}\n", j * i);
+    // (0 + 0i + 0j + -1k)
+
+    let q = Quaternion::new(1., 2., 3., 4.);
+    println!("{} {}\n", q.scalar(), q.vector().flat()[2]);
+    // 1 4
+    println!("{}\n", q * q.inverse().unwrap());
+    // (1 + 0i + 0j + 0k) (up to rounding)
+
+    let axis = Vector::from(vec![1., 1., 1.]);
+    let angle = 2. * std::f64::consts::FRAC_PI_3;
+    let q = Quaternion::from_axis_angle(&axis, angle).unwrap();
+    println!("{}\n", q.rotate(&Vector::from(vec![1., 0., 0.])).unwrap());
+    // [0]
+    // [1]
+    // [0]
+    println!("{}\n", q.to_rotation_matrix());
+    println!("{}\n", rotation(&axis, angle).unwrap());
+    // Same top-left 3x3 block
+
+    let back = Quaternion::from_rotation_matrix(&q.to_homogeneous()).unwrap();
+    println!("{}\n", (back - q).norm() < 1e-12);
+    // true
+
+    let q = Quaternion::from_euler(0.1, 0.2, 0.3);
+    println!("{:?}\n", q.to_euler());
+    // (0.1, 0.2, 0.3) (up to rounding)
+    let (axis, angle) = Quaternion::from_axis_angle(&Vector::from(vec![0., 0., 2.]), 0.5)
+        .unwrap()
+        .to_axis_angle();
+    println!("{}\n{}\n", axis, angle);
+    // [0]
+    // [0]
+    // [1]
+    // 0.5
+
+    let a = Quaternion::identity();
+    let b = Quaternion::from_axis_angle(&Vector::from(vec![0., 0., 1.]), std::f64::consts::PI / 2.)
+        .unwrap();
+    println!("{:?}\n", a.slerp(&b, 0.5).to_axis_angle().1);
+    // 0.7853981633974483
+}
@@ -0,0 +1,47 @@
+use crate::core::Vector;
+use crate::render::{Mesh, Image, Scene, Mode, render};
+use crate::transform::{Frustum, rotation_x, rotation_y, look_at};
+
+pub fn test_25() {
+    let mesh = Mesh::from_obj(include_str!("../../assets/cube.obj")).unwrap();
+    println!("{} vertices, {} triangles\n", mesh.vertices.len(), mesh.faces.len());
+    // 8 vertices, 12 triangles
+
+    let eye = Vector::from(vec![0., 0., 3.]);
+    let target = Vector::from(vec![0., 0., 0.]);
+    let up = Vector::from(vec![0., 1., 0.]);
+    let frustum = Frustum::perspective(60f32.to_radians(), 3. / 2., 0.1, Some(100.));
+    let scene = Scene {
+        mesh: &mesh,
+        model: rotation_y(0.6).mul_mat(rotation_x(0.4)),
+        view: look_at(&eye, &target, &up).unwrap(),
+        projection: frustum.matrix().unwrap(),
+        color: [255, 200, 80],
+    };
+
+    let goldens = [
+        (Mode::Wireframe, "wireframe.pgm", &include_bytes!("../../assets/golden_wireframe.pgm")[..]),
+        (Mode::Flat, "flat.ppm", &include_bytes!("../../assets/golden_flat.ppm")[..]),
+    ];
+    for (mode, path, golden) in goldens {
+        let image = render(&scene, mode, 96, 64).unwrap();
+        let (bytes, golden) = if mode == Mode::Wireframe {
+            (image.to_pgm(), Image::from_pnm(golden).unwrap())
+        } else {
+            (image.to_ppm(), Image::from_pnm(golden).unwrap())
+        };
+        let path = std::env::temp_dir().join(path);
+        std::fs::write(&path, &bytes).unwrap();
+        let rendered = Image::from_pnm(&std::fs::read(&path).unwrap()).unwrap();
+        println!("{:?}: {:?} pixels differ from the golden image", mode, rendered.diff(&golden, 0));
+        let (width, height) = rendered.shape();
+        let identical = rendered.shape() == golden.shape()
+            && (0..height).all(|y| (0..width).all(|x| rendered.get(x, y) == golden.get(x, y)));
+        println!("{:?}", identical);
+    }
+    println!();
+    // Wireframe: Some(0) pixels differ from the golden image
+    // true
+    // Flat: Some(0) pixels differ from the golden image
+    // true
+}
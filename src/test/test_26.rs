@@ -0,0 +1,60 @@
+use crate::core::{Matrix, Vector, CooMatrix, SparseMatrix};
+
+pub fn test_26() {
+    // 1D Laplacian of a 100000 points grid, far too big for Matrix
+    let n = 100_000;
+    let mut coo: CooMatrix<f64> = CooMatrix::new((n, n));
+    for i in 0..n {
+        coo.push(i, i, 2.);
+        if i > 0 {
+            coo.push(i, i - 1, -1.);
+        }
+        if i + 1 < n {
+            coo.push(i, i + 1, -1.);
+        }
+    }
+    let laplacian = coo.to_csr();
+    println!("{}\n", laplacian);
+    // Csr 100000x100000 with 299998 entries
+    let x = Vector::from((0..n).map(|i| (i * i) as f64).collect::<Vec<f64>>());
+    let y = laplacian.mul_vec(&x).flat();
+    println!("{} {} {}\n", y[0], y[1], y[n / 2]);
+    // -1 -2 -2
+
+    let u = Matrix::from(vec![
+        vec![1., 0., 2.],
+        vec![0., 0., 3.],
+        vec![4., 5., 0.],
+    ]);
+    let s = SparseMatrix::from(&u);
+    println!("{}\n{}\n", s, s.to_csc());
+    // Csr 3x3 with 5 entries
+    // Csc 3x3 with 5 entries
+    println!("{}\n", Matrix::from(&s.transpose()));
+    // [1, 0, 4]
+    // [0, 0, 5]
+    // [2, 3, 0]
+    println!("{}\n", s.to_csc().mul_mat(&u));
+    println!("{}\n", u.mul_mat(u.clone()));
+    // Same product
+    println!("{}\n", s.get(2, 1));
+    // 5
+    // Only exact zeros are dropped, small values are kept
+    println!("{}\n", SparseMatrix::from(&Matrix::from(vec![vec![1e-12, 0.], vec![0., 1e-300]])));
+    // Csr 2x2 with 2 entries
+
+    let market = s.to_matrix_market();
+    print!("{}", market);
+    // %%MatrixMarket matrix coordinate real general
+    // 3 3 5
+    // 1 1 1
+    // ...
+    let read: SparseMatrix<f64> = SparseMatrix::from_matrix_market(&market).unwrap();
+    println!("{}\n", Matrix::from(&read));
+    let symmetric = "%%MatrixMarket matrix coordinate real symmetric\n% lower triangle\n2 2 2\n1 1 4\n2 1 -1\n";
+    println!("{}\n", Matrix::from(&SparseMatrix::<f64>::from_matrix_market(symmetric).unwrap()));
+    // [4, -1]
+    // [-1, 0]
+    println!("{:?}\n", SparseMatrix::<f64>::from_matrix_market("%%MatrixMarket matrix array real general\n").err());
+    // Some("line 1: only the coordinate format is supported")
+}
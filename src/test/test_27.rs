@@ -0,0 +1,82 @@
+use crate::core::{Matrix, Vector, CooMatrix};
+use crate::iterative::{
+    IterativeOptions, LinearOperator, Preconditioner, Solution,
+    jacobi, gauss_seidel, sor, conjugate_gradient, bicgstab, gmres,
+};
+
+fn summary(name: &str, solution: Solution<f64>) {
+    let x = solution.x.flat();
+    println!(
+        "{}: converged {} in {} iterations, x[0] = {:.6}, last residual {:.1e}",
+        name,
+        solution.converged,
+        solution.iterations,
+        x[0],
+        solution.residuals.last().unwrap()
+    );
+}
+
+pub fn test_27() {
+    // Diagonally dominant system, solution (1, 2, -1)
+    let u = Matrix::from(vec![
+        vec![10., -1., 2.],
+        vec![-1., 11., -1.],
+        vec![2., -1., 10.],
+    ]);
+    let b = u.mul_vec(Vector::from(vec![1., 2., -1.]));
+    let options = IterativeOptions::new(1e-10, 100);
+    summary("Jacobi", jacobi(&u, &b, &options).unwrap());
+    summary("Gauss-Seidel", gauss_seidel(&u, &b, &options).unwrap());
+    summary("SOR", sor(&u, &b, 1.1, &options).unwrap());
+    summary("CG", conjugate_gradient(&u, &b, &options).unwrap());
+    summary("BiCGSTAB", bicgstab(&u, &b, &options).unwrap());
+    summary("GMRES(2)", gmres(&u, &b, 2, &options).unwrap());
+    // Every method converges to x[0] = 1.000000
+    // The same system scaled down: a tiny diagonal is still no zero diagonal
+    let tiny = u.clone() * 1e-12;
+    summary("Jacobi", jacobi(&tiny, &tiny.mul_vec(Vector::from(vec![1., 2., -1.])), &options).unwrap());
+    // Jacobi: converged true, x[0] = 1.000000
+    // Eliminating the first row leaves a zero in the last pivot of U
+    let singular = Matrix::from(vec![vec![1., 1.], vec![1., 1.]]);
+    let mut ilu = IterativeOptions::new(1e-10, 100);
+    ilu.preconditioner = Preconditioner::Ilu0;
+    println!("{:?}", gmres(&singular, &Vector::from(vec![1., 1.]), 2, &ilu).err());
+    // Some("A zero pivot appeared during ILU(0)")
+    println!();
+
+    // 2D Poisson problem on a 50 x 50 grid, stored sparse
+    let side = 50;
+    let n = side * side;
+    let mut coo: CooMatrix<f64> = CooMatrix::new((n, n));
+    for i in 0..side {
+        for j in 0..side {
+            let k = i * side + j;
+            coo.push(k, k, 4.);
+            if i > 0 { coo.push(k, k - side, -1.); }
+            if i + 1 < side { coo.push(k, k + side, -1.); }
+            if j > 0 { coo.push(k, k - 1, -1.); }
+            if j + 1 < side { coo.push(k, k + 1, -1.); }
+        }
+    }
+    let poisson = coo.to_csr();
+    let b = Vector::from(vec![1.; n]);
+    let mut options = IterativeOptions::new(1e-8, 2000);
+    summary("CG", conjugate_gradient(&poisson, &b, &options).unwrap());
+    options.preconditioner = Preconditioner::Jacobi;
+    summary("CG + Jacobi", conjugate_gradient(&poisson, &b, &options).unwrap());
+    options.preconditioner = Preconditioner::Ilu0;
+    summary("CG + ILU(0)", conjugate_gradient(&poisson, &b, &options).unwrap());
+    summary("BiCGSTAB + ILU(0)", bicgstab(&poisson, &b, &options).unwrap());
+    let solution = gmres(&poisson, &b, 30, &options).unwrap();
+    let ax = poisson.apply(&solution.x.flat());
+    let error = ax.iter().map(|v| (v - 1.).abs()).fold(0., f64::max);
+    println!("max |Ax - b| = {:.1e}", error);
+    // max |Ax - b| below 1e-6
+    summary("GMRES(30) + ILU(0)", solution);
+    // Jacobi changes nothing on a constant diagonal, ILU(0) halves the CG iterations
+    options.preconditioner = Preconditioner::None;
+    options.max_iterations = 10;
+    summary("Gauss-Seidel", gauss_seidel(&poisson, &b, &options).unwrap());
+    // converged false in 10 iterations
+    println!();
+}
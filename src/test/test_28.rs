@@ -0,0 +1,90 @@
+use crate::core::{Matrix, CooMatrix};
+use crate::iterative::{
+    IterativeOptions, Eigenpair,
+    power_iteration, inverse_iteration, rayleigh_quotient_iteration, lanczos, arnoldi,
+};
+
+fn summary(name: &str, pair: &Eigenpair<f64>) {
+    println!(
+        "{}: {:.6} ({} iterations, converged {}, residual {:.1e})",
+        name,
+        pair.value,
+        pair.iterations,
+        pair.converged,
+        pair.residuals.last().unwrap()
+    );
+}
+
+pub fn test_28() {
+    // Eigenvalues 2 - sqrt(2), 2 and 2 + sqrt(2)
+    let u: Matrix<f64> = Matrix::from(vec![
+        vec![2., -1., 0.],
+        vec![-1., 2., -1.],
+        vec![0., -1., 2.],
+    ]);
+    let options = IterativeOptions::new(1e-10, 500);
+    let pair = power_iteration(&u, &options).unwrap();
+    summary("Power iteration", &pair);
+    println!("{}", pair.vector);
+    // 3.414214, vector [-0.5, 0.707107, -0.5]
+    summary("Inverse iteration, shift 0", &inverse_iteration(&u, 0., &options).unwrap());
+    // 0.585786
+    summary("Rayleigh quotient iteration, shift 1.9", &rayleigh_quotient_iteration(&u, 1.9, &options).unwrap());
+    // 2.000000 in a few iterations
+    println!();
+
+    // PageRank of a 4 page web, damping 0.85
+    let links: Vec<Vec<usize>> = vec![vec![1, 2], vec![2], vec![0], vec![0, 2]];
+    let mut google: Matrix<f64> = Matrix::from(vec![vec![0.15 / 4.; 4]; 4]);
+    for (page, targets) in links.iter().enumerate() {
+        for &target in targets {
+            let value = google.get(target, page) + 0.85 / targets.len() as f64;
+            google.set(target, page, value);
+        }
+    }
+    let pair = power_iteration(&google, &options).unwrap();
+    summary("PageRank", &pair);
+    let total: f64 = pair.vector.flat().iter().sum();
+    let ranks: Vec<String> = pair.vector.flat().iter().map(|r| format!("{:.4}", r / total)).collect();
+    println!("ranks {}", ranks.join(" "));
+    // 1.000000, page 2 then page 0 rank highest
+    println!();
+
+    // 1D Laplacian of size 1000 (spectrum in (0, 4))
+    // with three large entries on the diagonal
+    let n = 1000;
+    let mut coo: CooMatrix<f64> = CooMatrix::new((n, n));
+    for i in 0..n {
+        let diagonal = match i {
+            100 => 12.,
+            500 => 11.,
+            900 => 10.,
+            _ => 2.,
+        };
+        coo.push(i, i, diagonal);
+        if i > 0 { coo.push(i, i - 1, -1.); }
+        if i + 1 < n { coo.push(i, i + 1, -1.); }
+    }
+    let operator = coo.to_csr();
+    for pair in lanczos(&operator, 3, 40, &IterativeOptions::new(1e-8, 0)).unwrap() {
+        summary("Lanczos", &pair);
+    }
+    // 12.198039, 11.219544 and 10.246211 (2 + sqrt((d - 2)^2 + 4) for d = 12, 11, 10), converged true
+    println!();
+
+    // Rotation by a quarter turn in the plane, scaling by 0.5 along z
+    let r: Matrix<f64> = Matrix::from(vec![
+        vec![0., -1., 0.],
+        vec![1., 0., 0.],
+        vec![0., 0., 0.5],
+    ]);
+    for (re, im) in arnoldi(&r, 3, 3, &IterativeOptions::new(1e-12, 0)).unwrap() {
+        println!("Arnoldi: {:.6} {:+.6}i", re, im);
+    }
+    // i, -i, then 0.5
+    // A 2 x 2 operator spans no more than 2 Krylov directions
+    let nilpotent: Matrix<f64> = Matrix::from(vec![vec![0., 1.], vec![0., 0.]]);
+    println!("{:?}", lanczos(&nilpotent, 3, 3, &IterativeOptions::new(1e-12, 0)).err());
+    // Some("The Krylov subspace is smaller than the number of eigenvalues")
+    println!();
+}
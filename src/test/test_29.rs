@@ -0,0 +1,68 @@
+use crate::core::{Matrix, Rational};
+use crate::operations::Operations;
+
+fn rational(rows: Vec<Vec<i64>>) -> Matrix<Rational> {
+    return Matrix::from(
+        rows.iter()
+            .map(|row| row.iter().map(|&v| Rational::from(v)).collect())
+            .collect::<Vec<Vec<Rational>>>(),
+    );
+}
+
+pub fn test_29() {
+    let a = Rational::new(1, 3);
+    let b: Rational = "-5/6".parse().unwrap();
+    println!("{} {} {} {}", a + b, a - b, a * b, a / b);
+    // -1/2 7/6 -5/18 -2/5
+    println!("{} {}", Rational::new(6, -4), Rational::new(10, 5));
+    // -3/2 2
+    println!("{} {} {} {}", b.numerator(), b.denominator(), b.recip().unwrap(), Rational::new(10, 5).is_integer());
+    // -5 6 -6/5 true
+    println!("{}", Rational::new(9, 4).sqrt());
+    // 3/2
+    // Rational::new(2, 1).sqrt() panics: "2 is not the square of a rational"
+    println!();
+
+    // Same matrix as the third inverse of test_12
+    let u = rational(vec![
+        vec![8, 5, -2],
+        vec![4, 7, 20],
+        vec![7, 6, 1],
+    ]);
+    println!("{}", u.inverse().unwrap());
+    // [113/174, 17/174, -19/29]
+    // [-68/87, -11/87, 28/29]
+    // [25/174, 13/174, -6/29]
+    println!("{}", u.inverse().unwrap().get(0, 0).to_f64());
+    // 0.6494252873563219
+    println!("{}", u.determinant());
+    // -174
+    println!("{}", u.mul_mat(u.inverse().unwrap()));
+    // Exactly the identity
+    println!();
+
+    let u = rational(vec![
+        vec![8, 5, -2, 4, 28],
+        vec![4, 2, 20, 4, -4],
+        vec![8, 5, 1, 4, 17],
+    ]);
+    println!("{}", u.row_echelon());
+    // [1, 0, 0, 3, 229/3]
+    // [0, 1, 0, -4, -118]
+    // [0, 0, 1, 0, -11/3]
+    println!("{}", u.rank());
+    // 3
+    println!();
+
+    // A wider backing for larger numerators and denominators
+    let hilbert: Matrix<Rational<i128>> = Matrix::from(
+        (1..=6)
+            .map(|r| (1..=6).map(|c| Rational::new(1, r + c - 1)).collect())
+            .collect::<Vec<Vec<Rational<i128>>>>(),
+    );
+    println!("{}", hilbert.determinant());
+    // 1/186313420339200000, the 6x6 Hilbert determinant
+    println!("{}", hilbert.inverse().unwrap().get(5, 5));
+    // 698544
+    println!();
+}
@@ -0,0 +1,54 @@
+use crate::core::{Matrix, BigInt, BigRational, Rational};
+
+pub fn test_30() {
+    let mut factorial: BigInt = BigInt::from(1);
+    for i in 1..=50 {
+        factorial = factorial * BigInt::from(i);
+    }
+    println!("{}", factorial);
+    // 30414093201713378043612608166064768844377641568960512000000000000
+    let a: BigInt = "123456789012345678901234567890".parse().unwrap();
+    let b: BigInt = "-9876543210987654321".parse().unwrap();
+    println!("{} {}", a.clone() / b.clone(), a.clone() % b.clone());
+    // -12499999886 925925941327160484 (truncated, as i64)
+    println!("{} {}", (a.clone() / b.clone()).is_negative(), (a % b).is_negative());
+    // true false
+    println!("{}", BigInt::from(2).pow(200) - BigInt::from(1));
+    // 1606938044258990275541962092341162602522202993782792835301375
+    // The limbs grow as needed, there is no fixed capacity
+    println!("{} bits", BigInt::from(3).pow(10000).bits());
+    // 15850 bits
+    println!();
+
+    // Pseudo-random entries in [-99, 99]: the numerators and denominators
+    // met during the elimination overflow i128 after a few rows
+    let n = 20;
+    let mut seed: i64 = 42;
+    let mut next = || {
+        seed = (seed * 1103515245 + 12345) % 2147483648;
+        return seed % 199 - 99;
+    };
+    let u: Matrix<BigRational> = Matrix::from(
+        (0..n)
+            .map(|_| (0..n).map(|_| Rational::from(BigInt::from(next()))).collect())
+            .collect::<Vec<Vec<BigRational>>>(),
+    );
+    let echelon = u.row_echelon_form();
+    let determinant = echelon.determinant().unwrap();
+    println!("rank {}, determinant of {} bits", echelon.rank(), determinant.numerator().bits());
+    println!("{}", determinant);
+    // rank 20, determinant of 148 bits
+    // 304702958808832556282378956982927954927987842
+    let inverse = u.inverse().unwrap();
+    let product = u.mul_mat(inverse);
+    let mut identity = true;
+    for r in 0..n {
+        for c in 0..n {
+            let expected = if r == c { BigInt::from(1) } else { BigInt::from(0) };
+            identity = identity && product.get(r, c) == Rational::from(expected);
+        }
+    }
+    println!("u * u^-1 is exactly the identity: {}", identity);
+    // true
+    println!();
+}
@@ -0,0 +1,86 @@
+use crate::core::{Matrix, BigInt};
+
+pub fn test_31() {
+    let u: Matrix<i32> = Matrix::from(vec![
+        vec![8, 5, -2, 4],
+        vec![4, 2, 20, 4],
+        vec![7, 6, 1, 4],
+        vec![3, -1, 5, 9],
+    ]);
+    println!("{}", u.determinant());
+    // -2084
+    let bareiss = u.bareiss();
+    println!("{}", bareiss.matrix);
+    // [8, 5, -2, 4]
+    // [0, -23, 46, 60]
+    // [0, 0, -460, -16]
+    // [0, 0, 0, -2084]
+    // with every cell a minor of u, hence an integer
+    println!("{:?}", bareiss.permutation);
+    // [0, 3, 1, 2]
+    // The products of two minors are taken in i64, only the result has to fit in i32
+    let u: Matrix<i32> = Matrix::from(vec![
+        vec![47, -31, 22, 45],
+        vec![-38, 41, 29, -17],
+        vec![33, 12, -49, 28],
+        vec![-26, 44, 35, -40],
+    ]);
+    println!("{} {}", u.determinant(), u.rank());
+    // 4793038 4
+    println!();
+
+    // Tiny float minors are still pivots
+    let u: Matrix<f64> = Matrix::from(vec![
+        vec![1e-4, 2e-4, 0.],
+        vec![3e-4, 1e-4, 0.],
+        vec![0., 0., 1e-4],
+    ]);
+    println!("{:e} {}", u.determinant(), u.rank());
+    // -5e-12 3
+    let u: Matrix<f64> = Matrix::from(vec![vec![1e-6, 0., 0.], vec![0., 1e-6, 0.], vec![0., 0., 1e-6]]);
+    println!("{:e} {}", u.determinant(), u.rank());
+    // 1e-18 3 (up to rounding)
+    // Singular, but its last float pivot is rounding residue: rank, the RREF
+    // and inverse all read it with the same pivot test
+    let u: Matrix<f64> = Matrix::from(vec![vec![0.1, 0.2, 0.3], vec![0.4, 0.5, 0.6], vec![0.7, 0.8, 0.9]]);
+    println!("{} {} {:?}", u.rank(), u.reduced_row_echelon_form().rank(), u.inverse().err());
+    // 2 2 Some("The matrix is singular")
+    println!();
+
+    // Rank of integer matrices, without truncated divisions
+    let u: Matrix<i64> = Matrix::from(vec![
+        vec![2, 4, 6, 8],
+        vec![3, 6, 9, 12],
+        vec![1, 1, 1, 1],
+    ]);
+    println!("{}", u.rank());
+    // 2
+    println!("{}", u.bareiss().matrix);
+    // [3, 6, 9, 12]
+    // [0, -3, -6, -9]
+    // [0, 0, 0, 0]
+    println!();
+
+    // Division-based eliminations need a field, they do not compile on integers:
+    // u.inverse(), u.row_echelon() or u.null_space() fail with
+    // "the trait bound `i64: Field` is not satisfied"
+
+    // Big integer determinant: the intermediates never exceed the size of a minor
+    let n = 40;
+    let mut seed: i64 = 7;
+    let u: Matrix<BigInt> = Matrix::from(
+        (0..n)
+            .map(|_| {
+                (0..n)
+                    .map(|_| {
+                        seed = (seed * 1103515245 + 12345) % 2147483648;
+                        BigInt::from(seed % 2001 - 1000)
+                    })
+                    .collect()
+            })
+            .collect::<Vec<Vec<BigInt>>>(),
+    );
+    println!("{}", u.determinant());
+    // -61094417571233941590154795815530286889347832119499471687723217267579822263316557867428093497886658293193136634370824562975041921803554
+    println!();
+}
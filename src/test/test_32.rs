@@ -0,0 +1,114 @@
+use crate::core::{Matrix, Vector, Gf, Gf2, Gf2Matrix};
+use crate::operations::Operations;
+
+fn gf7(rows: Vec<Vec<i64>>) -> Matrix<Gf<7>> {
+    return Matrix::from(
+        rows.iter()
+            .map(|row| row.iter().map(|&v| Gf::from(v)).collect())
+            .collect::<Vec<Vec<Gf<7>>>>(),
+    );
+}
+
+pub fn test_32() {
+    let (a, b): (Gf<7>, Gf<7>) = (Gf::new(3), Gf::new(5));
+    println!("{} {} {} {} {}", a + b, a - b, a * b, a / b, -a);
+    // 1 5 1 2 4
+    println!("{}", Gf::<7>::from(-1i64).value());
+    // 6
+    println!("{} {}", Gf::<7>::new(2).sqrt(), Gf::<13>::new(10).sqrt());
+    // 3 6 (3^2 = 9 = 2 mod 7, 6^2 = 36 = 10 mod 13)
+    // Gf::<6>::new(1) does not compile: "The modulus of Gf<P> must be a prime"
+    println!();
+
+    let u = gf7(vec![
+        vec![1, 2, 3],
+        vec![4, 5, 6],
+        vec![0, 1, 1],
+    ]);
+    let inverse = u.inverse().unwrap();
+    println!("{}", inverse);
+    // [2, 5, 6]
+    // [1, 5, 2]
+    // [6, 2, 6]
+    println!("{}", u.mul_mat(inverse));
+    // Identity
+    println!("{}", u.determinant());
+    // 3
+    let x = u.solve(&Vector::from(vec![Gf::new(1), Gf::new(0), Gf::new(2)])).unwrap();
+    println!("{}", x);
+    // [0]
+    // [5]
+    // [4]
+    println!();
+
+    // Over GF(7) the rows (1, 2, 3) and (3, 6, 2) are independent,
+    // but (1, 2, 3) and (3, 6, 9) are not
+    let u = gf7(vec![vec![1, 2, 3], vec![3, 6, 9], vec![2, 4, 6]]);
+    println!("rank {}, null space {}", u.rank(), u.null_space().len());
+    // rank 1, null space 2
+    println!();
+
+    // Hamming(7, 4): column j of the parity check matrix is j + 1 in binary
+    let mut h = Gf2Matrix::new((3, 7));
+    for j in 0..7 {
+        for bit in 0..3 {
+            h.set(bit, j, Gf2((j + 1) >> bit & 1 == 1));
+        }
+    }
+    println!("{}", h);
+    // 1010101
+    // 0110011
+    // 0001111
+    let codewords = h.null_space();
+    println!("{} codewords in the basis, rank {}", codewords.len(), h.rank());
+    // 4 codewords in the basis, rank 3
+
+    // Flip bit 5 of a codeword: the syndrome spells 5 + 1 in binary
+    let mut received = codewords[0].flat();
+    received[5] = received[5] + Gf2(true);
+    let syndrome = h.mul_vec(&Vector::from(received)).flat();
+    let position = (0..3).map(|bit| (syndrome[bit].0 as usize) << bit).sum::<usize>() - 1;
+    println!("error at bit {}", position);
+    // error at bit 5
+    let x = h.solve(&Vector::from(syndrome.clone())).unwrap();
+    println!("{:?} {}", h.transpose().shape(), h.mul_vec(&x).flat() == syndrome);
+    // (7, 3) true
+    println!();
+
+    // The same elimination through the generic Matrix code
+    let generic: Matrix<Gf2> = Matrix::from(&h);
+    println!("{}", generic.row_echelon());
+    // [1, 0, 1, 0, 1, 0, 1]
+    // [0, 1, 1, 0, 0, 1, 1]
+    // [0, 0, 0, 1, 1, 1, 1]
+    println!();
+
+    // 200 x 200 random matrix, packed into 4 words per row
+    let n = 200;
+    let mut seed: u64 = 1;
+    let mut random = Gf2Matrix::new((n, n));
+    for r in 0..n {
+        for c in 0..n {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            random.set(r, c, Gf2(seed >> 63 == 1));
+        }
+    }
+    println!("rank {}", random.rank());
+    // rank 198
+    let kernel = random.null_space();
+    let zero = Vector::from(vec![Gf2(false); n]);
+    let checks = kernel.iter().all(|x| random.mul_vec(x).flat() == zero.flat());
+    println!("{} null vectors, all mapped to zero: {}", kernel.len(), checks);
+    // 2 null vectors, all mapped to zero: true
+    match random.inverse() {
+        Ok(_) => println!("invertible"),
+        Err(error) => println!("{}", error),
+    }
+    // The matrix is singular
+    let (mut left, mut right) = (Gf2Matrix::identity(n), Gf2Matrix::identity(n));
+    left.xor_rows(0, 1);
+    right.xor_rows(1, 0);
+    println!("{}", left.mul_mat(&right).inverse().unwrap() == right.inverse().unwrap().mul_mat(&left.inverse().unwrap()));
+    // true, (LR)^-1 = R^-1 L^-1
+    println!();
+}
@@ -0,0 +1,75 @@
+use crate::core::{Matrix, Vector, BigInt, HermiteForm, SmithForm};
+use crate::operations::Operations;
+
+pub fn test_33() {
+    let a: Matrix<i64> = Matrix::from(vec![
+        vec![2, 3, 6, 2],
+        vec![5, 6, 1, 6],
+        vec![8, 3, 1, 1],
+    ]);
+    let hermite: HermiteForm<i64> = a.hermite_normal_form();
+    println!("{}", hermite.h);
+    // [1, 0, 50, -11]
+    // [0, 3, 28, -2]
+    // [0, 0, 61, -13]
+    println!("{}", hermite.u);
+    // [9, -5, 1]
+    // [5, -2, 0]
+    // [11, -6, 1]
+    println!("{}", hermite.u.mul_mat(a.clone()));
+    // Same as h
+    println!("|det u| = {}", hermite.u.determinant().abs());
+    // |det u| = 1
+    println!("{:?}", hermite.pivots);
+    // [(0, 0), (1, 1), (2, 2)]
+    println!();
+
+    // Z^3 / image of b is Z/2 x Z/6 x Z/12
+    let b: Matrix<i64> = Matrix::from(vec![
+        vec![2, 4, 4],
+        vec![-6, 6, 12],
+        vec![10, -4, -16],
+    ]);
+    let smith: SmithForm<i64> = b.smith_normal_form();
+    println!("{}", smith.d);
+    // [2, 0, 0]
+    // [0, 6, 0]
+    // [0, 0, 12]
+    let invariants: Vec<String> = smith.invariants.iter().map(|i| i.to_string()).collect();
+    println!("invariants {}", invariants.join(" | "));
+    // invariants 2 | 6 | 12
+    println!("{}", smith.u.mul_mat(b.clone()).mul_mat(smith.v.clone()));
+    // Same as d
+    println!();
+
+    // 3x + 6y + 9z = 12 and 2x - 4y + 6z = 8
+    let system: Matrix<i64> = Matrix::from(vec![vec![3, 6, 9], vec![2, -4, 6]]);
+    let x = system.solve_diophantine(&Vector::from(vec![12, 8])).unwrap();
+    println!("{}", x);
+    // [4]
+    // [0]
+    // [0]
+    println!("{}", system.mul_vec(x));
+    // [12]
+    // [8]
+    // 2x + 4y = 5 has no integer solution
+    let parity: Matrix<i64> = Matrix::from(vec![vec![2, 4]]);
+    println!("{:?}", parity.solve_diophantine(&Vector::from(vec![5])).err());
+    // Some("The system has no integer solution")
+    println!();
+
+    // Big integer cells: the invariants of a 6x6 matrix of powers
+    let powers: Matrix<BigInt> = Matrix::from(
+        (1..=6)
+            .map(|r: i64| (0..6).map(|c| BigInt::from(r).pow(4 * c)).collect())
+            .collect::<Vec<Vec<BigInt>>>(),
+    );
+    let smith = powers.smith_normal_form();
+    let invariants: Vec<String> = smith.invariants.iter().map(|i| i.to_string()).collect();
+    println!("invariants {}", invariants.join(" | "));
+    // invariants 1 | 1 | 240 | 327600 | 8019648000 | 142040651559206400000
+    let product = smith.invariants.iter().fold(BigInt::from(1), |p, i| p * i.clone());
+    println!("{}", product == powers.determinant().abs());
+    // true: the product of the invariants is |det|
+    println!();
+}
@@ -0,0 +1,116 @@
+use crate::core::{Matrix, Polynomial, Complex, Rational, Gf, Gf2};
+
+fn show_roots(roots: &[(f64, f64)]) {
+    let roots: Vec<String> = roots
+        .iter()
+        .map(|&(r, i)| if i == 0. { format!("{:.6}", r) } else { format!("{:.6}{:+.6}i", r, i) })
+        .collect();
+    println!("roots {}", roots.join(", "));
+}
+
+pub fn test_34() {
+    // (x - 1)(x - 2)(x - 3)
+    let p: Polynomial<f64> = Polynomial::from(vec![-1., 1.])
+        * Polynomial::from(vec![-2., 1.])
+        * Polynomial::from(vec![-3., 1.]);
+    println!("{}", p);
+    // x^3 - 6x^2 + 11x - 6
+    println!("p(4) = {}", p.eval(4.));
+    // p(4) = 6
+    println!("p' = {}", p.derivative());
+    // p' = 3x^2 - 12x + 11
+    let (quotient, remainder) = p.div_rem(&Polynomial::from(vec![-1., 1.])).unwrap();
+    println!("p / (x - 1) = {} remainder {}", quotient, remainder);
+    // p / (x - 1) = x^2 - 5x + 6 remainder 0
+    let mut q: Polynomial<f64> = Polynomial::monomial(2., 3) + Polynomial::from(vec![4.]);
+    println!("{} monic {}", q, q.monic().unwrap());
+    // 2x^3 + 4 monic x^3 + 2
+    q.scl(0.);
+    println!("{}", q.is_zero());
+    // true
+    show_roots(&p.roots().unwrap());
+    // roots 1.000000, 2.000000, 3.000000
+    show_roots(&Polynomial::from(vec![1., 0., 1.]).roots().unwrap());
+    // roots 0.000000-1.000000i, 0.000000+1.000000i
+    // (x - 1)^2 (x + 2), a repeated root
+    show_roots(&Polynomial::from(vec![2., -3., 0., 1.]).roots().unwrap());
+    // roots -2.000000, 1.000000, 1.000000
+    // (x - 1)(x - i) = x^2 - (1 + i)x + i
+    let q: Polynomial<Complex> = Polynomial::from(vec![
+        Complex::new(0., 1.),
+        Complex::new(-1., -1.),
+        Complex::new(1., 0.),
+    ]);
+    println!("{}", q);
+    // x^2 - (1 + 1i)x + (0 + 1i)
+    for root in q.roots().unwrap() {
+        println!("{:.4} {:+.4}i", root.real(), root.imaginary());
+    }
+    // 0.0000 +1.0000i
+    // 1.0000 -0.0000i
+    println!();
+
+    // Exact for integer matrices
+    let a: Matrix<i64> = Matrix::from(vec![
+        vec![2, -1, 0],
+        vec![-1, 2, -1],
+        vec![0, -1, 2],
+    ]);
+    let characteristic = a.characteristic_polynomial();
+    println!("{}", characteristic);
+    // x^3 - 6x^2 + 10x - 4
+    println!("-p(0) = {}, det = {}", -characteristic.eval(0), a.determinant());
+    // -p(0) = 4, det = 4
+    println!("{}", characteristic.eval_matrix(&a));
+    // Cayley-Hamilton: the zero matrix
+    let eigenvalues: Polynomial<f64> =
+        Polynomial::from(characteristic.coefficients().iter().map(|&c| c as f64).collect::<Vec<f64>>());
+    show_roots(&eigenvalues.roots().unwrap());
+    // roots 0.585786, 2.000000, 3.414214
+    println!();
+
+    // A Jordan block of 2 next to another 2 and a 3
+    let j: Matrix<Rational> = Matrix::from(
+        vec![
+            vec![2, 1, 0, 0],
+            vec![0, 2, 0, 0],
+            vec![0, 0, 2, 0],
+            vec![0, 0, 0, 3],
+        ]
+        .into_iter()
+        .map(|row| row.into_iter().map(Rational::from).collect())
+        .collect::<Vec<Vec<Rational>>>(),
+    );
+    println!("{}", j.characteristic_polynomial());
+    // x^4 - 9x^3 + 30x^2 - 44x + 24, that is (x - 2)^3 (x - 3)
+    let minimal = j.minimal_polynomial();
+    println!("{}", minimal);
+    // x^3 - 7x^2 + 16x - 12, that is (x - 2)^2 (x - 3)
+    println!("{}", minimal.eval_matrix(&j));
+    // The zero matrix
+    println!();
+
+    // No division by n, so characteristic 2 and 3 are fine on a 3 x 3 matrix
+    let g: Matrix<Gf2> = Matrix::from(vec![
+        vec![Gf2(true), Gf2(true), Gf2(false)],
+        vec![Gf2(false), Gf2(true), Gf2(true)],
+        vec![Gf2(true), Gf2(false), Gf2(true)],
+    ]);
+    let characteristic = g.characteristic_polynomial();
+    println!("{}", characteristic);
+    // x^3 + x^2 + x
+    println!("{}", characteristic.eval_matrix(&g));
+    // The zero matrix
+    let f: Matrix<Gf<3>> = Matrix::from(
+        vec![vec![1u64, 2, 0], vec![0, 1, 1], vec![2, 0, 1]]
+            .into_iter()
+            .map(|row| row.into_iter().map(Gf::<3>::new).collect())
+            .collect::<Vec<Vec<Gf<3>>>>(),
+    );
+    let characteristic = f.characteristic_polynomial();
+    println!("{}", characteristic);
+    // x^3 + 1
+    println!("{}", characteristic.eval_matrix(&f));
+    // The zero matrix
+    println!();
+}
@@ -0,0 +1,71 @@
+use crate::core::Matrix;
+
+fn show(m: &Matrix<f64>) {
+    let (rows, columns) = m.shape();
+    for r in 0..rows {
+        let row: Vec<String> = (0..columns).map(|c| format!("{:.6}", m.get(r, c))).collect();
+        println!("[{}]", row.join(", "));
+    }
+}
+
+fn largest_difference(a: &Matrix<f64>, b: &Matrix<f64>) -> f64 {
+    return (a.clone() - b.clone()).flat().iter().fold(0., |m: f64, x| m.max(x.abs()));
+}
+
+pub fn test_35() {
+    // Two-state weather Markov chain, rows are "from" states
+    let p: Matrix<f64> = Matrix::from(vec![vec![0.9, 0.1], vec![0.5, 0.5]]);
+    show(&p.pow(20).unwrap());
+    // Both rows tend to the stationary distribution [0.833333, 0.166667]
+    show(&p.pow(-1).unwrap().mul_mat(p.clone()));
+    // The identity
+    let fibonacci: Matrix<f64> = Matrix::from(vec![vec![1., 1.], vec![1., 0.]]);
+    println!("F(50) = {}", fibonacci.pow(50).unwrap().get(0, 1));
+    // F(50) = 12586269025
+    println!();
+
+    // x' = Ax with A a rotation generator: e^(At) rotates by t radians
+    let t = std::f64::consts::FRAC_PI_3;
+    let rotation: Matrix<f64> = Matrix::from(vec![vec![0., -t], vec![t, 0.]]);
+    show(&rotation.expm().unwrap());
+    // [0.500000, -0.866025]
+    // [0.866025, 0.500000]
+    let nilpotent: Matrix<f64> = Matrix::from(vec![vec![0., 1., 0.], vec![0., 0., 1.], vec![0., 0., 0.]]);
+    show(&nilpotent.expm().unwrap());
+    // I + N + N^2 / 2
+    // [1.000000, 1.000000, 0.500000]
+    // [0.000000, 1.000000, 1.000000]
+    // [0.000000, 0.000000, 1.000000]
+    let large: Matrix<f64> = Matrix::from(vec![vec![10., 0.], vec![0., -10.]]);
+    show(&large.expm().unwrap());
+    // e^10 = 22026.465795 and e^-10 = 0.000045
+    let infinite: Matrix<f64> = Matrix::from(vec![vec![f64::INFINITY, 0.], vec![0., 1.]]);
+    println!("{:?}", infinite.expm().err());
+    // Some("The matrix holds a non finite entry")
+    println!();
+
+    let a: Matrix<f64> = Matrix::from(vec![vec![4., 1.], vec![0., 9.]]);
+    let root = a.sqrtm().unwrap();
+    show(&root);
+    // [2.000000, 0.200000]
+    // [0.000000, 3.000000]
+    println!("{:.1e}", largest_difference(&root.mul_mat(root.clone()), &a));
+    // Within rounding of 0
+    let reflection: Matrix<f64> = Matrix::from(vec![vec![-1., 0.], vec![0., 1.]]);
+    println!("{:?}", reflection.sqrtm().err());
+    // Some("The square root iteration met a singular matrix")
+    println!();
+
+    let b: Matrix<f64> = Matrix::from(vec![vec![1., 2., 0.], vec![0., -1., 0.5], vec![0.25, 0., 0.5]]);
+    let logarithm = b.expm().unwrap().logm().unwrap();
+    println!("{:.1e}", largest_difference(&logarithm, &b));
+    // log(e^B) = B within rounding
+    show(&rotation.expm().unwrap().logm().unwrap());
+    // Back to the generator
+    // [0.000000, -1.047198]
+    // [1.047198, 0.000000]
+    let singular: Matrix<f64> = Matrix::from(vec![vec![1., 2.], vec![2., 4.]]);
+    println!("{:?}", singular.logm().err());
+    // Some("The logarithm of a singular matrix is not defined")
+    println!();
+}
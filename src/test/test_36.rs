@@ -0,0 +1,128 @@
+use crate::core::{Matrix, Schur};
+
+fn largest(m: &Matrix<f64>) -> f64 {
+    return m.flat().iter().fold(0., |l: f64, x| l.max(x.abs()));
+}
+
+fn transposed(m: &Matrix<f64>) -> Matrix<f64> {
+    let mut t = m.clone();
+    t.transpose();
+    return t;
+}
+
+pub fn test_36() {
+    let a: Matrix<f64> = Matrix::from(vec![vec![1., 2.], vec![3., 4.]]);
+    let b: Matrix<f64> = Matrix::from(vec![vec![0., 5.], vec![6., 7.]]);
+    println!("{}", a.kronecker(&b));
+    // [0, 5, 0, 10]
+    // [6, 7, 12, 14]
+    // [0, 15, 0, 20]
+    // [18, 21, 24, 28]
+    println!("{}", a.kronecker_sum(&b));
+    // [1, 5, 2, 0]
+    // [6, 8, 0, 2]
+    // [3, 0, 4, 5]
+    // [0, 3, 6, 11]
+    println!("{}", a.vec());
+    // [1]
+    // [3]
+    // [2]
+    // [4]
+    println!("{}", Matrix::unvec(&a.vec(), (2, 2)));
+    // Back to a
+    println!();
+
+    // Eigenvalues 1.654503, 3.550231 and the pair -0.102367 ± 1.086849i
+    let m: Matrix<f64> = Matrix::from(vec![
+        vec![0., -1., 2., 1.],
+        vec![1., 0., 1., 0.],
+        vec![0., 0., 2., 1.],
+        vec![1., 0., 0., 3.],
+    ]);
+    let schur: Schur<f64> = m.schur().unwrap();
+    println!("blocks {:?}", schur.blocks);
+    // blocks [(0, 2), (2, 1), (3, 1)]
+    for &(first, size) in &schur.blocks {
+        if size == 1 {
+            println!("{:.6}", schur.t.get(first, first));
+        } else {
+            let (a, b) = (schur.t.get(first, first), schur.t.get(first, first + 1));
+            let (c, d) = (schur.t.get(first + 1, first), schur.t.get(first + 1, first + 1));
+            let imaginary = (-(a - d) * (a - d) / 4. - b * c).sqrt();
+            println!("{:.6} ± {:.6}i", (a + d) / 2., imaginary);
+        }
+    }
+    // -0.102367 ± 1.086849i
+    // 3.550231
+    // 1.654503
+    let rebuilt = schur.u.mul_mat(schur.t.clone()).mul_mat(transposed(&schur.u));
+    println!("{:.1e}", largest(&(rebuilt - m.clone())));
+    // Within rounding of 0: u t u^T == m
+    let orthogonality = transposed(&schur.u).mul_mat(schur.u.clone());
+    println!("{:.1e}", (0..4).map(|i| (orthogonality.get(i, i) - 1.).abs()).fold(0., f64::max));
+    // Within rounding of 0: u^T u == I
+    println!();
+
+    // Small enough for the Kronecker form: 2 x 3 unknowns
+    let c: Matrix<f64> = Matrix::from(vec![vec![1., 0., 2.], vec![0., 3., 1.]]);
+    let b3: Matrix<f64> = Matrix::from(vec![vec![2., 1., 0.], vec![0., 3., 1.], vec![1., 0., 4.]]);
+    let x = a.solve_sylvester(&b3, &c).unwrap();
+    let residual = a.mul_mat(x.clone()) + x.mul_mat(b3.clone()) - c.clone();
+    println!("{:.1e}", largest(&residual));
+    // Within rounding of 0
+
+    // Bartels-Stewart: 6 x 5 unknowns, with complex eigenvalues on both sides
+    let a6: Matrix<f64> = Matrix::from(
+        (0..6)
+            .map(|r| (0..6).map(|c| ((r * 7 + c * 3) % 11) as f64 - 5. + if r == c { 8. } else { 0. }).collect())
+            .collect::<Vec<Vec<f64>>>(),
+    );
+    let b5: Matrix<f64> = Matrix::from(
+        (0..5)
+            .map(|r| (0..5).map(|c| ((r * 5 + c * 2) % 7) as f64 - 3. + if r == c { 4. } else { 0. }).collect())
+            .collect::<Vec<Vec<f64>>>(),
+    );
+    let c65: Matrix<f64> = Matrix::from(
+        (0..6).map(|r| (0..5).map(|c| (r + 2 * c) as f64).collect()).collect::<Vec<Vec<f64>>>(),
+    );
+    let x = a6.solve_sylvester(&b5, &c65).unwrap();
+    let residual = a6.mul_mat(x.clone()) + x.mul_mat(b5.clone()) - c65.clone();
+    println!("{:.1e}", largest(&residual) / largest(&x));
+    // Within rounding of 0
+    // A and -A share every eigenvalue
+    println!("{:?}", a.solve_sylvester(&(a.clone() * -1.), &a).err());
+    // Some("The equation has no unique solution")
+    println!();
+
+    // Stable system x' = A x, Gramian from the input matrix B B^T
+    let stable: Matrix<f64> = Matrix::from(vec![
+        vec![-1., 2., 0., 0., 0.],
+        vec![-2., -1., 0., 0., 0.],
+        vec![0., 1., -3., 1., 0.],
+        vec![0., 0., 0., -2., 1.],
+        vec![1., 0., 0., 0., -4.],
+    ]);
+    let input: Matrix<f64> = Matrix::from(vec![vec![1.], vec![0.], vec![0.], vec![1.], vec![1.]]);
+    let q = input.mul_mat(transposed(&input));
+    let gramian = stable.solve_lyapunov(&q).unwrap();
+    let residual = stable.mul_mat(gramian.clone()) + gramian.mul_mat(transposed(&stable)) + q.clone();
+    println!("{:.1e}", largest(&residual));
+    // Within rounding of 0
+    println!("{:.1e}", largest(&(gramian.clone() - transposed(&gramian))));
+    // Within rounding of 0: the Gramian is symmetric
+
+    // Discrete time: the same matrix scaled inside the unit disk
+    let discrete = stable.clone() * 0.2;
+    let x = discrete.solve_discrete_lyapunov(&q).unwrap();
+    let residual = discrete.mul_mat(x.clone()).mul_mat(transposed(&discrete)) - x.clone() + q.clone();
+    println!("{:.1e}", largest(&residual));
+    // Within rounding of 0
+    // The same equation through the Kronecker form: (A ⊗ A - I) vec(X) = -vec(Q)
+    let small: Matrix<f64> = Matrix::from(vec![vec![0.5, 0.1], vec![0., -0.3]]);
+    let identity: Matrix<f64> = Matrix::from(vec![vec![1., 0.], vec![0., 1.]]);
+    println!("{}", small.solve_discrete_lyapunov(&identity).unwrap());
+    // [1.344163083293518, -0.028666985188724316]
+    // [-0.028666985188724316, 1.0989010989010988]
+    // that is [[8440/6279, -60/2093], [-60/2093, 100/91]]
+    println!();
+}
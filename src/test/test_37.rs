@@ -0,0 +1,101 @@
+use crate::core::{Matrix, Rational};
+
+pub fn test_37() {
+    let a: Matrix<i64> = Matrix::from(vec![
+        vec![2, -1, 3],
+        vec![0, 4, 1],
+        vec![5, 2, -2],
+    ]);
+    println!("minor(0, 0) = {}, cofactor(0, 1) = {}", a.minor(0, 0), a.cofactor(0, 1));
+    // minor(0, 0) = -10, cofactor(0, 1) = 5
+    println!("{}", a.cofactor_matrix());
+    // [-10, 5, -20]
+    // [4, -19, -9]
+    // [-13, -2, 8]
+    let adjugate = a.adjugate();
+    println!("{}", adjugate);
+    // [-10, 4, -13]
+    // [5, -19, -2]
+    // [-20, -9, 8]
+    println!("{}", a.mul_mat(adjugate));
+    // det(a) * I
+    // [-85, 0, 0]
+    // [0, -85, 0]
+    // [0, 0, -85]
+    println!("{:?}", a.adjugate_inverse().err());
+    // Some("The inverse does not exist over these scalars")
+    println!();
+
+    // Determinant 1, so the inverse stays integral
+    let unimodular: Matrix<i64> = Matrix::from(vec![
+        vec![2, 3, 1],
+        vec![1, 2, 1],
+        vec![1, 1, 1],
+    ]);
+    let inverse = unimodular.adjugate_inverse().unwrap();
+    println!("{}", inverse);
+    // [1, -2, 1]
+    // [0, 1, -1]
+    // [-1, 1, 1]
+    println!("{}", unimodular.mul_mat(inverse));
+    // [1, 0, 0]
+    // [0, 1, 0]
+    // [0, 0, 1]
+    println!();
+
+    // Rationals agree with Gauss-Jordan elimination
+    let q: Matrix<Rational> = Matrix::from(
+        vec![vec![8, 5, -2], vec![4, 7, 20], vec![7, 6, 1]]
+            .into_iter()
+            .map(|row| row.into_iter().map(Rational::from).collect())
+            .collect::<Vec<Vec<Rational>>>(),
+    );
+    let by_adjugate = q.adjugate_inverse().unwrap();
+    let by_elimination = q.inverse().unwrap();
+    println!("{}", by_adjugate);
+    // [113/174, 17/174, -19/29]
+    // [-68/87, -11/87, 28/29]
+    // [25/174, 13/174, -6/29]
+    println!("{}", (0..9).all(|k| by_adjugate.get(k / 3, k % 3) == by_elimination.get(k / 3, k % 3)));
+    // true
+    println!();
+
+    // Rank n - 1: the adjugate has rank 1
+    let singular: Matrix<i64> = Matrix::from(vec![
+        vec![1, 2, 3],
+        vec![4, 5, 6],
+        vec![7, 8, 9],
+    ]);
+    let adjugate = singular.adjugate();
+    println!("{}", adjugate);
+    // [-3, 6, -3]
+    // [6, -12, 6]
+    // [-3, 6, -3]
+    println!("rank {}", adjugate.rank());
+    // rank 1
+    println!("{:?}", singular.adjugate_inverse().err());
+    // Some("The matrix is singular")
+    println!();
+
+    // Floating points divide like any field, whatever the rounding
+    let mut seed: i64 = 3;
+    let mut inverted = 0;
+    for _ in 0..200 {
+        let rows: Vec<Vec<f64>> = (0..3)
+            .map(|_| {
+                (0..3)
+                    .map(|_| {
+                        seed = (seed * 1103515245 + 12345) % 2147483648;
+                        (seed % 2001 - 1000) as f64 + 0.5
+                    })
+                    .collect()
+            })
+            .collect();
+        if Matrix::from(rows).adjugate_inverse().is_ok() {
+            inverted += 1;
+        }
+    }
+    println!("{} of 200 inverted", inverted);
+    // 200 of 200 inverted
+    println!();
+}
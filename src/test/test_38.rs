@@ -0,0 +1,123 @@
+use crate::core::{Matrix, Vector, Rational};
+use crate::geometry::{
+    Orientation, Location,
+    simplex_determinant, simplex_volume, signed_area, signed_volume,
+    orientation_2d, orientation_3d, in_circle,
+};
+
+pub fn test_38() {
+    // 2x + y - z = 8, -3x - y + 2z = -11, -2x + y + 2z = -3
+    let a: Matrix<i64> = Matrix::from(vec![
+        vec![2, 1, -1],
+        vec![-3, -1, 2],
+        vec![-2, 1, 2],
+    ]);
+    println!("{}", a.solve_cramer(&Vector::from(vec![8, -11, -3])).unwrap());
+    // [2]
+    // [3]
+    // [-1]
+    let q: Matrix<Rational> = Matrix::from(vec![
+        vec![Rational::from(2), Rational::from(1)],
+        vec![Rational::from(1), Rational::from(3)],
+    ]);
+    println!("{}", q.solve_cramer(&Vector::from(vec![Rational::from(1), Rational::from(0)])).unwrap());
+    // [3/5]
+    // [-1/5]
+    let integer: Matrix<i64> = Matrix::from(vec![vec![2, 1], vec![1, 3]]);
+    println!("{:?}", integer.solve_cramer(&Vector::from(vec![1, 0])).err());
+    // Some("The solution does not exist over these scalars")
+    let singular: Matrix<f64> = Matrix::from(vec![vec![1., 2.], vec![2., 4.]]);
+    println!("{:?}", singular.solve_cramer(&Vector::from(vec![1., 1.])).err());
+    // Some("The matrix is singular")
+    // Floating points are a field: a rounded x_i is no failure
+    let mut seed: i64 = 11;
+    let mut next = || {
+        seed = (seed * 1103515245 + 12345) % 2147483648;
+        return (seed % 2001 - 1000) as f64 + 0.5;
+    };
+    let mut solved = 0;
+    for _ in 0..200 {
+        let rows: Vec<Vec<f64>> = (0..3).map(|_| (0..3).map(|_| next()).collect()).collect();
+        let b: Vec<f64> = (0..3).map(|_| next()).collect();
+        if Matrix::from(rows).solve_cramer(&Vector::from(b)).is_ok() {
+            solved += 1;
+        }
+    }
+    println!("{} of 200 solved", solved);
+    // 200 of 200 solved
+    println!();
+
+    let (o, x, y) = (
+        Vector::from(vec![0., 0.]),
+        Vector::from(vec![4., 0.]),
+        Vector::from(vec![0., 3.]),
+    );
+    println!("{} {}", signed_area(&o, &x, &y), signed_area(&o, &y, &x));
+    // 6 -6
+    let unit: Vec<Vector<f64>> = vec![
+        Vector::from(vec![0., 0., 0.]),
+        Vector::from(vec![1., 0., 0.]),
+        Vector::from(vec![0., 1., 0.]),
+        Vector::from(vec![0., 0., 1.]),
+    ];
+    println!("{:.6}", signed_volume(&unit[0], &unit[1], &unit[2], &unit[3]));
+    // 0.166667
+    // A segment is the 1-simplex, its signed length
+    println!("{}", simplex_volume(&[&Vector::from(vec![5.]), &Vector::from(vec![2.])]));
+    // -3
+    // Integer coordinates stay exact before the division by n!
+    let lattice: Vec<Vector<i64>> = vec![
+        Vector::from(vec![0, 0]),
+        Vector::from(vec![3, 1]),
+        Vector::from(vec![1, 2]),
+    ];
+    println!("{}", simplex_determinant(&[&lattice[0], &lattice[1], &lattice[2]]));
+    // 5, twice the area 5/2
+    println!();
+
+    let (a, b) = (Vector::from(vec![0, 0]), Vector::from(vec![10, 0]));
+    let tests = [
+        orientation_2d(&a, &b, &Vector::from(vec![3, 1])),
+        orientation_2d(&a, &b, &Vector::from(vec![3, -1])),
+        orientation_2d(&a, &b, &Vector::from(vec![30, 0])),
+    ];
+    println!("{:?}", tests);
+    // [Positive, Negative, Degenerate]
+    let above = Vector::from(vec![0., 0., 2.]);
+    let below = Vector::from(vec![1., 1., -2.]);
+    println!(
+        "{:?} {:?} {:?}",
+        orientation_3d(&unit[0], &unit[1], &unit[2], &above),
+        orientation_3d(&unit[0], &unit[1], &unit[2], &below),
+        orientation_3d(&unit[0], &unit[1], &unit[2], &Vector::from(vec![5., -7., 0.])),
+    );
+    // Positive Negative Degenerate
+    println!("{}", orientation_2d(&a, &b, &Vector::from(vec![5, 5])) == Orientation::Positive);
+    // true
+    println!();
+
+    // The circle through (0, 0), (4, 0) and (0, 4) has center (2, 2) and radius^2 8
+    let (p, q, r) = (Vector::from(vec![0, 0]), Vector::from(vec![4, 0]), Vector::from(vec![0, 4]));
+    for d in [vec![2, 2], vec![4, 4], vec![5, 5], vec![-1, 1]] {
+        let location = in_circle(&p, &q, &r, &Vector::from(d.clone())).unwrap();
+        println!("{:?} {:?}", d, location);
+    }
+    // [2, 2] Inside
+    // [4, 4] Boundary
+    // [5, 5] Outside
+    // [-1, 1] Outside
+    // The order of the three points does not matter
+    println!("{}", in_circle(&p, &r, &q, &Vector::from(vec![2, 2])).unwrap() == Location::Inside);
+    // true
+    println!("{:?}", in_circle(&p, &q, &Vector::from(vec![8, 0]), &r).err());
+    // Some("The three points are collinear")
+    // A tiny triangle is still a triangle
+    let (p, q, r) = (Vector::from(vec![0., 0.]), Vector::from(vec![1e-6, 0.]), Vector::from(vec![0., 1e-6]));
+    println!(
+        "{:?} {:?}",
+        orientation_2d(&p, &q, &r),
+        in_circle(&p, &q, &r, &Vector::from(vec![2e-7, 2e-7]))
+    );
+    // Positive Ok(Inside)
+    println!();
+}
@@ -0,0 +1,136 @@
+use crate::core::{Matrix, Vector, UpperTriangular, LowerTriangular, Diagonal, Tridiagonal, Banded};
+
+pub fn test_39() {
+    let dense: Matrix<f64> = Matrix::from(vec![
+        vec![2., -1., 3.],
+        vec![0., 4., 1.],
+        vec![0., 0., -5.],
+    ]);
+    let upper = UpperTriangular::try_from(&dense).unwrap();
+    println!("det {}", upper.determinant());
+    // det -40
+    let x = upper.solve(&Vector::from(vec![4., 9., -10.])).unwrap();
+    println!("{}", x);
+    // [-0.125]
+    // [1.75]
+    // [2]
+    println!("{}", upper.mul_vec(&x));
+    // [4]
+    // [9]
+    // [-10]
+    let lower: LowerTriangular<f64> = upper.transpose();
+    println!("{} {:?}", upper.size(), lower.mul_vec(&Vector::from(vec![1., 1., 1.])).flat());
+    // 3 [2.0, 3.0, -1.0]
+    println!("{}", lower);
+    // [2, 0, 0]
+    // [-1, 4, 0]
+    // [3, 1, -5]
+    println!("{}", lower.solve(&Vector::from(vec![2., 3., -1.])).unwrap());
+    // [1]
+    // [1]
+    // [1]
+    println!("{:?}", LowerTriangular::try_from(&dense).err());
+    // Some("The matrix has non-zero cells above its diagonal")
+    println!();
+
+    let d: Diagonal<f64> = Diagonal::from(vec![2., -4., 0.5]);
+    println!("det {}", d.determinant());
+    // det -4
+    println!("{} {:?} {:?}", lower.size(), d.diagonal(), d.mul_vec(&Vector::from(vec![1., 1., 1.])).flat());
+    // 3 [2.0, -4.0, 0.5] [2.0, -4.0, 0.5]
+    println!("{}", d.inverse().unwrap());
+    // [0.5, 0, 0]
+    // [0, -0.25, 0]
+    // [0, 0, 2]
+    println!("{}", d.mul_mat(&dense));
+    // [4, -2, 6]
+    // [-0, -16, -4]
+    // [0, 0, -2.5]
+    println!("{:?}", Diagonal::from(vec![1., 0.]).solve(&Vector::from(vec![1., 1.])).err());
+    // Some("The matrix is singular")
+    println!();
+
+    // -u'' = 1 on 1000 points: tridiag(-1, 2, -1) x = 1 has x_i = i (n + 1 - i) / 2
+    let n = 1000;
+    let poisson: Tridiagonal<f64> = Tridiagonal::new(vec![-1.; n - 1], vec![2.; n], vec![-1.; n - 1]);
+    let x = poisson.solve(&Vector::from(vec![1.; n])).unwrap().flat();
+    println!("x_500 = {:.6}", x[499]);
+    // x_500 = 125250.000000
+    println!("det {}", poisson.determinant());
+    // det 1001
+    // The determinant needs no division, so integers work too
+    let integer: Tridiagonal<i64> = Tridiagonal::new(vec![1, 2, 3], vec![4, 5, 6, 7], vec![-1, -2, -3]);
+    println!("det {} = {}", integer.determinant(), Matrix::from(&integer).determinant());
+    // det 1183 = 1183
+    println!();
+
+    // Pentadiagonal, the bandwidths are read from the non-zero cells
+    let rows: Vec<Vec<f64>> = (0..6)
+        .map(|r: i64| {
+            (0..6)
+                .map(|c: i64| match (r - c).abs() {
+                    0 => 6.,
+                    1 => -4.,
+                    2 => 1.,
+                    _ => 0.,
+                })
+                .collect()
+        })
+        .collect();
+    let dense: Matrix<f64> = Matrix::from(rows);
+    let banded = Banded::try_from(&dense).unwrap();
+    println!("size {}, bandwidths {:?}", banded.size(), banded.bandwidths());
+    // size 6, bandwidths (2, 2)
+    println!("det {:.6} = {:.6}", banded.determinant(), dense.determinant());
+    // det 336.000000 = 336.000000
+    let b = Vector::from(vec![1., 2., 3., 4., 5., 6.]);
+    let x = banded.solve(&b).unwrap();
+    let residual = (banded.mul_vec(&x) - b).flat().iter().fold(0., |m: f64, r| m.max(r.abs()));
+    println!("{:.1e}", residual);
+    // Within rounding of 0
+
+    // A zero leading pivot: the elimination swaps rows within the band
+    let swapped: Matrix<f64> = Matrix::from(vec![vec![0., 1.], vec![1., 0.]]);
+    let banded = Banded::try_from(&swapped).unwrap();
+    println!("det {}", banded.determinant());
+    // det -1
+    println!("{:?}", banded.solve(&Vector::from(vec![2., 3.])).unwrap().flat());
+    // [3.0, 2.0]
+    let tridiagonal: Tridiagonal<f64> = Tridiagonal::try_from(&swapped).unwrap();
+    println!("{:?}", tridiagonal.solve(&Vector::from(vec![2., 3.])).unwrap().flat());
+    // [3.0, 2.0]
+    // Pivoting on the band of a random matrix matches the dense solve
+    let mut seed: i64 = 5;
+    let rows: Vec<Vec<f64>> = (0..8)
+        .map(|r: i64| {
+            (0..8)
+                .map(|c: i64| {
+                    seed = (seed * 1103515245 + 12345) % 2147483648;
+                    if c - r > 1 || r - c > 2 { 0. } else { (seed % 19 - 9) as f64 }
+                })
+                .collect()
+        })
+        .collect();
+    let dense: Matrix<f64> = Matrix::from(rows);
+    let banded = Banded::try_from(&dense).unwrap();
+    let b = Vector::from(vec![1.; 8]);
+    let (x, y) = (banded.solve(&b).unwrap().flat(), dense.solve(&b).unwrap().flat());
+    println!("{:?} {}", banded.bandwidths(), x.iter().zip(&y).all(|(a, b)| (a - b).abs() < 1e-9));
+    // (2, 1) true
+    println!("{:.6} {:.6}", banded.determinant(), dense.determinant());
+    // Same determinant
+    // Small cells are still cells: the structure is only read from exact zeros
+    let tiny: Matrix<f64> = Matrix::from(vec![vec![1., 0., 1e-12], vec![0., 1., 0.], vec![0., 0., 1.]]);
+    println!("{:?} {:?}", Banded::try_from(&tiny).unwrap().bandwidths(), Tridiagonal::try_from(&tiny).err());
+    // (0, 2) Some("The matrix has non-zero cells outside its three diagonals")
+    println!("{:?}", LowerTriangular::try_from(&tiny).err());
+    // Some("The matrix has non-zero cells above its diagonal")
+    let mut tridiagonal: Tridiagonal<f64> = Tridiagonal::new(vec![1.], vec![3., 3.], vec![0.]);
+    tridiagonal.set(0, 1, 2.);
+    println!("{:?}", tridiagonal.mul_vec(&Vector::from(vec![1., 1.])).flat());
+    // [5.0, 4.0]
+    println!("{}", Banded::from(&tridiagonal));
+    // [3, 2]
+    // [1, 3]
+    println!();
+}
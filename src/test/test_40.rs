@@ -0,0 +1,128 @@
+use crate::core::{Matrix, Vector, SymmetricMatrix, Rational, Gf};
+
+pub fn test_40() {
+    // I + X X^T, built column by column of X
+    let x: Matrix<f64> = Matrix::from(vec![
+        vec![1., 2.],
+        vec![0., 1.],
+        vec![1., 0.],
+    ]);
+    let mut s: SymmetricMatrix<f64> = SymmetricMatrix::new(3);
+    for i in 0..3 {
+        s.set(i, i, 1.);
+    }
+    s.rank_k_update(1., &x);
+    println!("{}", s);
+    // [6, 2, 1]
+    // [2, 2, 0]
+    // [1, 0, 2]
+    let mut t: SymmetricMatrix<f64> = SymmetricMatrix::new(3);
+    for i in 0..3 {
+        t.set(i, i, 1.);
+    }
+    t.rank_one_update(1., &Vector::from(vec![1., 0., 1.]));
+    t.rank_one_update(1., &Vector::from(vec![2., 1., 0.]));
+    println!("{}", t == s);
+    // true
+    println!("{} {:?}", s.size(), s.mul_vec(&Vector::from(vec![1., 1., 1.])).flat());
+    // 3 [9.0, 4.0, 3.0]
+    println!("det {:.6}", s.determinant());
+    // det 14.000000
+    println!();
+
+    let a: Matrix<f64> = Matrix::from(vec![
+        vec![4., 2., -2.],
+        vec![2., 10., 2.],
+        vec![-2., 2., 6.],
+    ]);
+    println!("{} {}", a.is_symmetric(), a.is_skew_symmetric());
+    // true false
+    let l = a.cholesky().unwrap();
+    println!("{}", l);
+    // [2, 0, 0]
+    // [1, 3, 0]
+    // [-1, 1, 2]
+    let packed = SymmetricMatrix::try_from(&a).unwrap();
+    println!("det {} = {}^2", packed.determinant(), l.determinant());
+    // det 144 = 12^2
+    let (unit, d) = packed.ldlt().unwrap();
+    println!("{}", unit);
+    println!("{:?}", d.diagonal());
+    // [1, 0, 0]
+    // [0.5, 1, 0]
+    // [-0.5, 0.3333333333333333, 1]
+    // [4.0, 9.0, 4.0]
+    println!("{}", packed.solve(&Vector::from(vec![4., 14., 6.])).unwrap());
+    // [1]
+    // [1]
+    // [1]
+    println!();
+
+    // LDL^T takes no square root, so it stays exact over rationals and finite fields
+    let q: SymmetricMatrix<Rational> = SymmetricMatrix::try_from(&Matrix::from(vec![
+        vec![Rational::from(2), Rational::from(1)],
+        vec![Rational::from(1), Rational::from(2)],
+    ]))
+    .unwrap();
+    println!("det {}", q.determinant());
+    // det 3
+    println!("{}", q.solve(&Vector::from(vec![Rational::from(3), Rational::from(3)])).unwrap());
+    // [1]
+    // [1]
+    let g: SymmetricMatrix<Gf<7>> = SymmetricMatrix::try_from(&Matrix::from(vec![
+        vec![Gf::new(3), Gf::new(1)],
+        vec![Gf::new(1), Gf::new(2)],
+    ]))
+    .unwrap();
+    println!("det {}", g.determinant());
+    // det 5
+    println!();
+
+    // A zero leading minor, so solve falls back to the dense elimination
+    let indefinite: SymmetricMatrix<f64> = SymmetricMatrix::try_from(&Matrix::from(vec![
+        vec![0., 1.],
+        vec![1., 0.],
+    ]))
+    .unwrap();
+    println!("{:?}", indefinite.cholesky().err());
+    // Some("The matrix is not positive definite")
+    println!("{}", indefinite.solve(&Vector::from(vec![3., 5.])).unwrap());
+    // [5]
+    // [3]
+    println!("det {}", indefinite.determinant());
+    // det -1
+    // Every leading minor is nonzero, but the first pivot is tiny: L D L^T would
+    // round 1 - 1e20 to -1e20 and lose x[0], the pivoted elimination does not
+    let tiny: SymmetricMatrix<f64> = SymmetricMatrix::try_from(&Matrix::from(vec![
+        vec![1e-20, 1.],
+        vec![1., 1.],
+    ]))
+    .unwrap();
+    println!("{:?}", tiny.solve(&Vector::from(vec![1., 2.])).unwrap().flat());
+    // [1.0, 1.0]
+    println!();
+
+    let (values, vectors) = SymmetricMatrix::try_from(&Matrix::from(vec![
+        vec![2., -1., 0.],
+        vec![-1., 2., -1.],
+        vec![0., -1., 2.],
+    ]))
+    .unwrap()
+    .eigen(1e-12);
+    println!("{:.6?}", values);
+    // [0.585786, 2.000000, 3.414214]
+    let first: Vec<f64> = (0..3).map(|r| f64::abs(vectors.get(r, 0))).collect();
+    println!("{:.6?}", first);
+    // [0.500000, 0.707107, 0.500000]
+    println!();
+
+    let skew: Matrix<i64> = Matrix::from(vec![
+        vec![0, 3, -1],
+        vec![-3, 0, 2],
+        vec![1, -2, 0],
+    ]);
+    println!("{} {}", skew.is_symmetric(), skew.is_skew_symmetric());
+    // false true
+    println!("{:?}", SymmetricMatrix::try_from(&skew).err());
+    // Some("The matrix is not symmetric")
+}
@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+pub mod transform;
+pub mod projection;
+
+pub use transform::*;
+pub use projection::*;
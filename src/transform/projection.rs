@@ -0,0 +1,172 @@
+// Perspective projections, in the column-vector convention of transform.rs:
+// the camera looks down its -z axis and clip.w == -z.
+use crate::core::Matrix;
+use crate::operations::{Operations, Trigonometry};
+
+// Range the visible depths are mapped to, after the perspective divide
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Depth {
+    // OpenGL
+    NegativeOneToOne,
+    // Vulkan, Direct3D, Metal
+    ZeroToOne,
+}
+
+// Order in which the cells are laid out in memory
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Layout {
+    RowMajor,
+    // What OpenGL expects (e.g. glUniformMatrix4fv with transpose = GL_FALSE)
+    ColumnMajor,
+}
+
+// View volume between the near and far planes, clipped by
+// [left, right] x [bottom, top] on the near plane
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Frustum<K> {
+    pub left: K,
+    pub right: K,
+    pub bottom: K,
+    pub top: K,
+    pub near: K,
+    // None for an infinite far plane
+    pub far: Option<K>,
+    pub depth: Depth,
+    // Maps the near plane to the far end of the depth range and vice versa
+    pub reversed: bool,
+}
+
+impl<
+        K: std::fmt::Display
+            + std::ops::Add<Output = K>
+            + std::ops::Sub<Output = K>
+            + std::ops::Mul<Output = K>
+            + std::ops::Div<Output = K>
+            + std::cmp::PartialEq
+            + std::cmp::PartialOrd
+            + std::ops::Neg<Output = K>
+            + Default
+            + Copy
+            + Operations
+            + Trigonometry
+    > Frustum<K>
+{
+    // Off-centre frustum, with OpenGL depth
+    pub fn new(left: K, right: K, bottom: K, top: K, near: K, far: Option<K>) -> Frustum<K> {
+        return Frustum {
+            left,
+            right,
+            bottom,
+            top,
+            near,
+            far,
+            depth: Depth::NegativeOneToOne,
+            reversed: false,
+        };
+    }
+
+    // Symmetric frustum from the vertical field of view (in radians)
+    // and the width / height ratio, with OpenGL depth
+    pub fn perspective(fov: K, ratio: K, near: K, far: Option<K>) -> Frustum<K> {
+        let two = K::one() + K::one();
+        let top = near * (fov / two).tan();
+        let right = top * ratio;
+        return Frustum::new(-right, right, -top, top, near, far);
+    }
+
+    // Depths (near, far) are mapped to
+    fn depth_range(&self) -> (K, K) {
+        let (near, far) = match self.depth {
+            Depth::NegativeOneToOne => (-K::one(), K::one()),
+            Depth::ZeroToOne => (K::default(), K::one()),
+        };
+        if self.reversed {
+            return (far, near);
+        }
+        return (near, far);
+    }
+
+    // Coefficients (A, B) of the third row, such that
+    // (A * z + B) / -z maps -near and -far to the depth range
+    fn depth_coefficients(&self) -> Result<(K, K), &'static str> {
+        let (dn, df) = self.depth_range();
+        let n = self.near;
+        if n <= K::default() {
+            return Err("The near plane must be in front of the camera");
+        }
+        return match self.far {
+            Some(f) => {
+                if f <= n {
+                    return Err("The far plane must be behind the near plane");
+                }
+                let b = (dn - df) * n * f / (f - n);
+                Ok((b / n - dn, b))
+            }
+            None => Ok((-df, (dn - df) * n)),
+        };
+    }
+
+    // Time: O(1) − Space: O(1)
+    pub fn matrix(&self) -> Result<Matrix<K>, &'static str> {
+        let (l, r, b, t, n) = (self.left, self.right, self.bottom, self.top, self.near);
+        if (r - l).is_zero() || (t - b).is_zero() {
+            return Err("The bounds of the frustum must be distinct");
+        }
+        let (depth_a, depth_b) = self.depth_coefficients()?;
+        let two = K::one() + K::one();
+
+        let mut m: Matrix<K> = Matrix::from(vec![vec![K::default(); 4]; 4]);
+        m.set(0, 0, two * n / (r - l));
+        m.set(0, 2, (r + l) / (r - l));
+        m.set(1, 1, two * n / (t - b));
+        m.set(1, 2, (t + b) / (t - b));
+        m.set(2, 2, depth_a);
+        m.set(2, 3, depth_b);
+        m.set(3, 2, -K::one());
+        return Ok(m);
+    }
+
+    // Closed form inverse of matrix(), mapping clip space back to view space
+    // Time: O(1) − Space: O(1)
+    pub fn inverse(&self) -> Result<Matrix<K>, &'static str> {
+        let m = self.matrix()?;
+        let (x, y) = (m.get(0, 0), m.get(1, 1));
+        let (depth_a, depth_b) = (m.get(2, 2), m.get(2, 3));
+
+        let mut inverse: Matrix<K> = Matrix::from(vec![vec![K::default(); 4]; 4]);
+        inverse.set(0, 0, K::one() / x);
+        inverse.set(0, 3, m.get(0, 2) / x);
+        inverse.set(1, 1, K::one() / y);
+        inverse.set(1, 3, m.get(1, 2) / y);
+        inverse.set(2, 3, -K::one());
+        inverse.set(3, 2, K::one() / depth_b);
+        inverse.set(3, 3, depth_a / depth_b);
+        return Ok(inverse);
+    }
+}
+
+// Cells of m in the given memory order
+// Time: O(n) − Space: O(n)
+// where n is the number of elements in the matrix
+pub fn to_layout<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+>(m: &Matrix<K>, layout: Layout) -> Vec<K> {
+    return match layout {
+        Layout::RowMajor => m.flat(),
+        Layout::ColumnMajor => {
+            let mut transposed = m.clone();
+            transposed.transpose();
+            transposed.flat()
+        }
+    };
+}
@@ -0,0 +1,315 @@
+// 4x4 homogeneous transforms.
+// They follow the column-vector convention of mul_vec (p' = M * p),
+// so translations live in the last column. OpenGL reads column-major
+// arrays, which is the row-major `data` of the transposed matrix.
+use crate::core::{Matrix, Vector};
+use crate::operations::{Operations, Trigonometry};
+
+pub fn identity<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+        + Trigonometry
+>() -> Matrix<K> {
+    let mut m: Matrix<K> = Matrix::from(vec![vec![K::default(); 4]; 4]);
+    for i in 0..4 {
+        m.set(i, i, K::one());
+    }
+    return m;
+}
+
+pub fn translation<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+        + Trigonometry
+>(x: K, y: K, z: K) -> Matrix<K> {
+    let mut m: Matrix<K> = identity();
+    m.set(0, 3, x);
+    m.set(1, 3, y);
+    m.set(2, 3, z);
+    return m;
+}
+
+pub fn scaling<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+        + Trigonometry
+>(x: K, y: K, z: K) -> Matrix<K> {
+    let mut m: Matrix<K> = identity();
+    m.set(0, 0, x);
+    m.set(1, 1, y);
+    m.set(2, 2, z);
+    return m;
+}
+
+// Counter-clockwise rotations (when looking down the axis towards the origin),
+// angles are in radians
+pub fn rotation_x<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+        + Trigonometry
+>(angle: K) -> Matrix<K> {
+    let (sin, cos) = (angle.sin(), angle.cos());
+    let mut m: Matrix<K> = identity();
+    m.set(1, 1, cos);
+    m.set(1, 2, -sin);
+    m.set(2, 1, sin);
+    m.set(2, 2, cos);
+    return m;
+}
+
+pub fn rotation_y<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+        + Trigonometry
+>(angle: K) -> Matrix<K> {
+    let (sin, cos) = (angle.sin(), angle.cos());
+    let mut m: Matrix<K> = identity();
+    m.set(0, 0, cos);
+    m.set(0, 2, sin);
+    m.set(2, 0, -sin);
+    m.set(2, 2, cos);
+    return m;
+}
+
+pub fn rotation_z<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+        + Trigonometry
+>(angle: K) -> Matrix<K> {
+    let (sin, cos) = (angle.sin(), angle.cos());
+    let mut m: Matrix<K> = identity();
+    m.set(0, 0, cos);
+    m.set(0, 1, -sin);
+    m.set(1, 0, sin);
+    m.set(1, 1, cos);
+    return m;
+}
+
+// Rodrigues' rotation formula around any (non-zero) axis
+pub fn rotation<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+        + Trigonometry
+>(axis: &Vector<K>, angle: K) -> Result<Matrix<K>, &'static str> {
+    if axis.shape().0 != 3 {
+        return Err("The axis must have shape (3, 1)");
+    }
+    let a = axis.normalize()?.flat();
+    let (x, y, z) = (a[0], a[1], a[2]);
+    let (sin, cos) = (angle.sin(), angle.cos());
+    let t = K::one() - cos;
+
+    let mut m: Matrix<K> = identity();
+    m.set(0, 0, t * x * x + cos);
+    m.set(0, 1, t * x * y - sin * z);
+    m.set(0, 2, t * x * z + sin * y);
+    m.set(1, 0, t * x * y + sin * z);
+    m.set(1, 1, t * y * y + cos);
+    m.set(1, 2, t * y * z - sin * x);
+    m.set(2, 0, t * x * z - sin * y);
+    m.set(2, 1, t * y * z + sin * x);
+    m.set(2, 2, t * z * z + cos);
+    return Ok(m);
+}
+
+// View matrix of a camera at `eye` looking at `target`,
+// right-handed: the camera looks down its -z axis (as gluLookAt)
+pub fn look_at<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+        + Trigonometry
+>(eye: &Vector<K>, target: &Vector<K>, up: &Vector<K>) -> Result<Matrix<K>, &'static str> {
+    let mut forward = target.clone();
+    forward.sub(eye);
+    let forward = forward
+        .normalize()
+        .map_err(|_| "The eye and the target must be distinct")?;
+    let right = forward
+        .cross(up)?
+        .normalize()
+        .map_err(|_| "The up vector must not be parallel to the view direction")?;
+    let up = right.cross(&forward)?;
+
+    let (f, r, u) = (forward.flat(), right.flat(), up.flat());
+    let mut m: Matrix<K> = identity();
+    for c in 0..3 {
+        m.set(0, c, r[c]);
+        m.set(1, c, u[c]);
+        m.set(2, c, -f[c]);
+    }
+    m.set(0, 3, -right.dot(eye.clone()));
+    m.set(1, 3, -up.dot(eye.clone()));
+    m.set(2, 3, forward.dot(eye.clone()));
+    return Ok(m);
+}
+
+// Maps the box [left, right] x [bottom, top] x [-near, -far]
+// to the OpenGL clip cube [-1, 1]^3
+pub fn orthographic<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+        + Trigonometry
+>(left: K, right: K, bottom: K, top: K, near: K, far: K) -> Result<Matrix<K>, &'static str> {
+    if (right - left).is_zero() || (top - bottom).is_zero() || (far - near).is_zero() {
+        return Err("The bounds of the box must be distinct");
+    }
+    let two = K::one() + K::one();
+
+    let mut m: Matrix<K> = identity();
+    m.set(0, 0, two / (right - left));
+    m.set(1, 1, two / (top - bottom));
+    m.set(2, 2, -two / (far - near));
+    m.set(0, 3, -(right + left) / (right - left));
+    m.set(1, 3, -(top + bottom) / (top - bottom));
+    m.set(2, 3, -(far + near) / (far - near));
+    return Ok(m);
+}
+
+// Applies m to the point p = (x, y, z, 1), then divides by w
+// Time: O(1) − Space: O(1)
+pub fn transform_point<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+        + Trigonometry
+>(m: &Matrix<K>, p: &Vector<K>) -> Result<Vector<K>, &'static str> {
+    let h = homogeneous(m, p, K::one())?;
+    if h[3].is_zero() {
+        return Err("The point is mapped to infinity (w = 0)");
+    }
+    return Ok(Vector::from(vec![h[0] / h[3], h[1] / h[3], h[2] / h[3]]));
+}
+
+// Applies m to the direction d = (x, y, z, 0), ignoring translations
+// Time: O(1) − Space: O(1)
+pub fn transform_direction<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+        + Trigonometry
+>(m: &Matrix<K>, d: &Vector<K>) -> Result<Vector<K>, &'static str> {
+    let h = homogeneous(m, d, K::default())?;
+    return Ok(Vector::from(vec![h[0], h[1], h[2]]));
+}
+
+fn homogeneous<
+    K: std::fmt::Display
+        + std::ops::Add<Output = K>
+        + std::ops::Sub<Output = K>
+        + std::ops::Mul<Output = K>
+        + std::ops::Div<Output = K>
+        + std::cmp::PartialEq
+        + std::cmp::PartialOrd
+        + std::ops::Neg<Output = K>
+        + Default
+        + Copy
+        + Operations
+        + Trigonometry
+>(m: &Matrix<K>, v: &Vector<K>, w: K) -> Result<Vec<K>, &'static str> {
+    if m.shape() != (4, 4) {
+        return Err("The transform must have shape (4, 4)");
+    }
+    if v.shape().0 != 3 {
+        return Err("The vector must have shape (3, 1)");
+    }
+    let mut h = v.flat();
+    h.push(w);
+    return Ok(m.mul_vec(Vector::from(h)).flat());
+}